@@ -0,0 +1,120 @@
+//! Submatrices, rows and columns, and joining matrices together
+//!
+//! These only move values around, so they work for any dtype. Positions are checked at runtime
+//! like indexing, while the sizes of blocks and stacked matrices are checked at compile time.
+
+use std::fmt::Debug;
+use crate::prelude::{ColVector, Matrix, RowVector};
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
+	/// Return the `R`×`C` block of the matrix with its top left value at `(row, col)`
+	///
+	/// This will panic if the block doesn't fit inside the matrix, like indexing out of bounds
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+	/// assert_eq!(a.submatrix::<2, 2>(1, 0), Matrix::new([[4, 5], [7, 8]]))
+	/// ```
+	pub fn submatrix<const R: usize, const C: usize>(&self, row: usize, col: usize) -> Matrix<R, C, L> {
+		assert!(row + R <= T && col + C <= N, "A {}×{} block at ({}, {}) is out of bounds for a {}×{} matrix", R, C, row, col, T, N);
+		Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| self[(row + i, col + j)])))
+	}
+	
+	/// Replace the block of the matrix with its top left value at `(row, col)`
+	///
+	/// This will panic if the block doesn't fit inside the matrix
+	/// ```
+	/// # use lineas::Matrix;
+	/// let mut a = Matrix::<3, 3, i32>::empty();
+	/// a.set_block(1, 1, &Matrix::new([[1, 2], [3, 4]]));
+	/// assert_eq!(a, Matrix::new([[0, 0, 0], [0, 1, 2], [0, 3, 4]]))
+	/// ```
+	pub fn set_block<const R: usize, const C: usize>(&mut self, row: usize, col: usize, block: &Matrix<R, C, L>) {
+		assert!(row + R <= T && col + C <= N, "A {}×{} block at ({}, {}) is out of bounds for a {}×{} matrix", R, C, row, col, T, N);
+		for i in 0..R {
+			for j in 0..C {
+				self[(row + i, col + j)] = block[(i, j)]
+			}
+		}
+	}
+	
+	/// Return row `i` of the matrix
+	///
+	/// This will panic if `i` is out of bounds
+	/// ```
+	/// # use lineas::{Matrix, RowVector};
+	/// assert_eq!(Matrix::new([[1, 2], [3, 4]]).row(1), RowVector::new([3, 4]))
+	/// ```
+	pub fn row(&self, i: usize) -> RowVector<N, L> {
+		RowVector::new(self.0[i])
+	}
+	
+	/// Return column `j` of the matrix
+	///
+	/// This will panic if `j` is out of bounds
+	/// ```
+	/// # use lineas::{ColVector, Matrix};
+	/// assert_eq!(Matrix::new([[1, 2], [3, 4]]).column(1), ColVector::new([2, 4]))
+	/// ```
+	pub fn column(&self, j: usize) -> ColVector<T, L> {
+		ColVector::new(self.0.map(|row| row[j]))
+	}
+	
+	/// Join two matrices with the same number of rows side by side
+	///
+	/// Stable Rust can't calculate the output size from the input sizes, so it has to be given as
+	/// `C`, usually through a type annotation. This will fail to compile if `C≠N+P`.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2], [3, 4]]);
+	/// let b: Matrix<2, 3, _> = a.hstack(&Matrix::new([[5], [6]]));
+	/// assert_eq!(b, Matrix::new([[1, 2, 5], [3, 4, 6]]))
+	/// ```
+	pub fn hstack<const P: usize, const C: usize>(&self, other: &Matrix<T, P, L>) -> Matrix<T, C, L> {
+		const { assert!(C == N + P, "Joining a T×N and a T×P matrix side by side gives a T×(N+P) matrix") }
+		Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| if j < N { self[(i, j)] } else { other[(i, j - N)] })))
+	}
+	
+	/// Join two matrices with the same number of columns one above the other
+	///
+	/// Like [`hstack`][Matrix::hstack], the output size has to be given as `R` and this will fail
+	/// to compile if `R≠T+P`.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2], [3, 4]]);
+	/// let b: Matrix<3, 2, _> = a.vstack(&Matrix::new([[5, 6]]));
+	/// assert_eq!(b, Matrix::new([[1, 2], [3, 4], [5, 6]]))
+	/// ```
+	pub fn vstack<const P: usize, const R: usize>(&self, other: &Matrix<P, N, L>) -> Matrix<R, N, L> {
+		const { assert!(R == T + P, "Joining a T×N and a P×N matrix one above the other gives a (T+P)×N matrix") }
+		Matrix::new(std::array::from_fn(|i| if i < T { self.0[i] } else { other.0[i - T] }))
+	}
+	
+	/// Swap rows `i` and `j` of the matrix in place
+	///
+	/// This will panic if either row is out of bounds
+	/// ```
+	/// # use lineas::Matrix;
+	/// let mut a = Matrix::new([[1, 2], [3, 4]]);
+	/// a.swap_rows(0, 1);
+	/// assert_eq!(a, Matrix::new([[3, 4], [1, 2]]))
+	/// ```
+	pub fn swap_rows(&mut self, i: usize, j: usize) {
+		self.0.swap(i, j)
+	}
+	
+	/// Swap columns `i` and `j` of the matrix in place
+	///
+	/// This will panic if either column is out of bounds
+	/// ```
+	/// # use lineas::Matrix;
+	/// let mut a = Matrix::new([[1, 2], [3, 4]]);
+	/// a.swap_cols(0, 1);
+	/// assert_eq!(a, Matrix::new([[2, 1], [4, 3]]))
+	/// ```
+	pub fn swap_cols(&mut self, i: usize, j: usize) {
+		for row in self.0.iter_mut() {
+			row.swap(i, j)
+		}
+	}
+}
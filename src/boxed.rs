@@ -0,0 +1,259 @@
+//! Implementations for the heap allocated [`BoxedMatrix`]
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use conv::{ConvUtil, ValueFrom};
+use crate::prelude::{BoxedMatrix, DMatrix, Matrix};
+use crate::scalar::{One, Scalar, Zero};
+use crate::Error;
+use crate::ops::determinant;
+
+impl<const T: usize, const N: usize, L: Copy + Debug> BoxedMatrix<T, N, L> {
+	/// Generate a new matrix with `f(i, j)` in row `i` and column `j`
+	///
+	/// The matrix is built one row at a time directly on the heap
+	/// ```
+	/// # use lineas::{BoxedMatrix, Matrix};
+	/// let a = BoxedMatrix::<2, 2, _>::from_fn(|i, j| i * 2 + j);
+	/// assert_eq!(Matrix::from(a), Matrix::new([[0, 1], [2, 3]]))
+	/// ```
+	pub fn from_fn<F: FnMut(usize, usize) -> L>(mut f: F) -> Self {
+		let rows = (0..T).map(|i| std::array::from_fn(|j| f(i, j))).collect::<Vec<[L; N]>>();
+		Self(rows.into_boxed_slice().try_into().unwrap())
+	}
+	
+	/// Generate an empty matrix
+	pub fn empty() -> Self where L: Zero {
+		let rows = vec![[L::zero(); N]; T];
+		Self(rows.into_boxed_slice().try_into().unwrap())
+	}
+	
+	/// Transform the type of the data to the specified type
+	///
+	/// This has the same requirements as [`Matrix::dtype`]
+	pub fn dtype<Q>(&self) -> BoxedMatrix<T, N, Q> where Q: Copy + Debug + ValueFrom<L> {
+		self.try_dtype().expect("Values could not be converted to the new dtype")
+	}
+	
+	/// Transform the type of the data to the specified type
+	///
+	/// Returns [`Error::ConversionOverflow`] if any value can't be represented in the new dtype
+	pub fn try_dtype<Q>(&self) -> Result<BoxedMatrix<T, N, Q>, Error> where Q: Copy + Debug + ValueFrom<L> {
+		let values = self.0.iter().flatten().map(|v| (*v).value_as::<Q>()).collect::<Result<Vec<Q>, _>>()
+			.map_err(|_| Error::ConversionOverflow)?;
+		Ok(BoxedMatrix::from_fn(|i, j| values[i * N + j]))
+	}
+	
+	/// Transpose the matrix
+	///
+	/// Returns the transposed matrix, leaving the original unchanged
+	pub fn transpose(&self) -> BoxedMatrix<N, T, L> {
+		BoxedMatrix::from_fn(|i, j| self[(j, i)])
+	}
+	
+	/// Scale the matrix
+	///
+	/// Returns the scaled matrix and does not alter the original
+	pub fn scale<V>(&self, v: V) -> Self where L: Mul<Output=L> + ValueFrom<V> {
+		let mut out = self.clone();
+		out.scale_set(v);
+		out
+	}
+	
+	/// Scale the matrix in place
+	pub fn scale_set<V>(&mut self, v: V) where L: Mul<Output=L> + ValueFrom<V> {
+		let multiplier: L = v.value_as().unwrap();
+		for value in self.0.iter_mut().flatten() {
+			*value = *value * multiplier
+		}
+	}
+	
+	/// Swap rows `i` and `j` of the matrix in place
+	///
+	/// This will panic if either row is out of bounds
+	pub fn swap_rows(&mut self, i: usize, j: usize) {
+		self.0.swap(i, j)
+	}
+	
+	/// Return the largest absolute value in the matrix
+	pub fn max_abs(&self) -> f64 where L: Scalar {
+		self.0.iter().flatten().map(|v| v.modulus()).fold(0., f64::max)
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> BoxedMatrix<T, T, L> {
+	/// Generate an identity matrix
+	pub fn identity() -> Self where L: Zero + One {
+		let mut out = Self::empty();
+		for i in 0..T {
+			out[(i, i)] = L::one()
+		}
+		out
+	}
+	
+	/// Returns the determinant of the matrix
+	///
+	/// This uses the same algorithms as [`Matrix::determinant`]
+	pub fn determinant(&self) -> L where L: Scalar {
+		determinant(self)
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Index<(usize, usize)> for BoxedMatrix<T, N, L> {
+	type Output = L;
+	
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		&self.0[index.0][index.1]
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> IndexMut<(usize, usize)> for BoxedMatrix<T, N, L> {
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		&mut self.0[index.0][index.1]
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> AddAssign<&BoxedMatrix<T, N, L>> for BoxedMatrix<T, N, L> where L: AddAssign {
+	fn add_assign(&mut self, rhs: &BoxedMatrix<T, N, L>) {
+		for (value, other) in self.0.iter_mut().flatten().zip(rhs.0.iter().flatten()) {
+			*value += *other
+		}
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> AddAssign for BoxedMatrix<T, N, L> where L: AddAssign {
+	fn add_assign(&mut self, rhs: Self) {
+		*self += &rhs
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Add for &BoxedMatrix<T, N, L> where L: AddAssign {
+	type Output = BoxedMatrix<T, N, L>;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out += rhs;
+		out
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Add for BoxedMatrix<T, N, L> where L: AddAssign {
+	type Output = Self;
+	
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += &rhs;
+		self
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> SubAssign<&BoxedMatrix<T, N, L>> for BoxedMatrix<T, N, L> where L: SubAssign {
+	fn sub_assign(&mut self, rhs: &BoxedMatrix<T, N, L>) {
+		for (value, other) in self.0.iter_mut().flatten().zip(rhs.0.iter().flatten()) {
+			*value -= *other
+		}
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> SubAssign for BoxedMatrix<T, N, L> where L: SubAssign {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self -= &rhs
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Sub for &BoxedMatrix<T, N, L> where L: SubAssign {
+	type Output = BoxedMatrix<T, N, L>;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out -= rhs;
+		out
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Sub for BoxedMatrix<T, N, L> where L: SubAssign {
+	type Output = Self;
+	
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= &rhs;
+		self
+	}
+}
+
+impl<const T: usize, const N: usize, const P: usize, L: Copy + Debug> Mul<&BoxedMatrix<N, P, L>> for &BoxedMatrix<T, N, L> where L: AddAssign + Mul<Output=L> + Zero {
+	type Output = BoxedMatrix<T, P, L>;
+	
+	/// Multiply two matrices together
+	///
+	/// The result is written straight into a new heap allocation
+	fn mul(self, rhs: &BoxedMatrix<N, P, L>) -> Self::Output {
+		let mut out = BoxedMatrix::empty();
+		for i in 0..T {
+			for k in 0..N {
+				let value = self[(i, k)];
+				for j in 0..P {
+					out[(i, j)] += value * rhs[(k, j)]
+				}
+			}
+		}
+		out
+	}
+}
+
+impl<const T: usize, const N: usize, const P: usize, L: Copy + Debug> Mul<BoxedMatrix<N, P, L>> for BoxedMatrix<T, N, L> where L: AddAssign + Mul<Output=L> + Zero {
+	type Output = BoxedMatrix<T, P, L>;
+	
+	fn mul(self, rhs: BoxedMatrix<N, P, L>) -> Self::Output {
+		&self * &rhs
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Neg for &BoxedMatrix<T, N, L> where L: Neg<Output=L> {
+	type Output = BoxedMatrix<T, N, L>;
+	
+	fn neg(self) -> Self::Output {
+		-self.clone()
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Neg for BoxedMatrix<T, N, L> where L: Neg<Output=L> {
+	type Output = Self;
+	
+	fn neg(mut self) -> Self::Output {
+		for value in self.0.iter_mut().flatten() {
+			*value = -*value
+		}
+		self
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> From<Matrix<T, N, L>> for BoxedMatrix<T, N, L> {
+	fn from(value: Matrix<T, N, L>) -> Self {
+		Self(Box::new(value.0))
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> From<BoxedMatrix<T, N, L>> for Matrix<T, N, L> {
+	fn from(value: BoxedMatrix<T, N, L>) -> Self {
+		Matrix::new(*value.0)
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> From<BoxedMatrix<T, N, L>> for DMatrix<L> {
+	fn from(value: BoxedMatrix<T, N, L>) -> Self {
+		DMatrix::new(T, N, value.0.iter().flatten().copied().collect())
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> TryFrom<DMatrix<L>> for BoxedMatrix<T, N, L> {
+	type Error = Error;
+	
+	/// Convert a runtime sized matrix into a boxed matrix
+	///
+	/// Returns [`Error::DimensionMismatch`] if the matrix isn't `T`×`N`
+	fn try_from(value: DMatrix<L>) -> Result<Self, Self::Error> {
+		if value.shape() != (T, N) {
+			return Err(Error::DimensionMismatch { expected: (T, N), found: value.shape() })
+		}
+		Ok(Self::from_fn(|i, j| value[(i, j)]))
+	}
+}
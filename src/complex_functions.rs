@@ -0,0 +1,274 @@
+//! Elementary functions for floating point [`Complex`] values
+//!
+//! Multivalued functions return their principal value. Each branch cut is listed on the function
+//! along with the side it's continuous from, which is the value returned for inputs lying exactly
+//! on the cut.
+
+use crate::Complex;
+
+/// Implement the complex function library for each floating point type
+macro_rules! complex_functions {
+	($($t:ident),*) => {$(
+		impl Complex<$t> {
+			/// Make a new complex value from polar coordinates `r·e^(iθ)`
+			pub fn from_polar(r: $t, theta: $t) -> Self {
+				Self {
+					real: r * theta.cos(),
+					imaginary: r * theta.sin()
+				}
+			}
+			
+			/// Return the modulus `|z|`
+			///
+			/// This avoids overflow in the intermediate values where possible
+			/// ```
+			/// # use lineas::Complex;
+			/// assert_eq!(Complex::from_complex(3f64, -4.).abs(), 5.)
+			/// ```
+			pub fn abs(&self) -> $t {
+				self.real.hypot(self.imaginary)
+			}
+			
+			/// Return the squared modulus `|z|²`, which is cheaper than [`abs`](Self::abs)
+			pub fn norm_sqr(&self) -> $t {
+				self.real * self.real + self.imaginary * self.imaginary
+			}
+			
+			/// Return the argument of the value in the range `(-π, π]`
+			///
+			/// The branch cut is along the negative real axis. A negative zero imaginary part gives
+			/// `-π` instead of `π`
+			pub fn arg(&self) -> $t {
+				self.imaginary.atan2(self.real)
+			}
+			
+			/// Return the polar coordinates `(r, θ)` of the value, with `θ` given by [`arg`](Self::arg)
+			pub fn to_polar(&self) -> ($t, $t) {
+				(self.abs(), self.arg())
+			}
+			
+			/// Return the reciprocal `1/z`
+			///
+			/// The result is not finite if the value is zero
+			pub fn recip(&self) -> Self {
+				let denominator = self.norm_sqr();
+				Self {
+					real: self.real / denominator,
+					imaginary: -self.imaginary / denominator
+				}
+			}
+			
+			/// Return the `n` complex roots of unity `e^(2πik/n)` for `k = 0, ..., n-1`
+			///
+			/// The first root is always exactly 1
+			/// ```
+			/// # use lineas::Complex;
+			/// let roots = Complex::<f64>::roots_of_unity(4);
+			/// assert_eq!(roots.len(), 4);
+			/// assert!((roots[1] - Complex::from_complex(0., 1.)).abs() < 1e-15)
+			/// ```
+			pub fn roots_of_unity(n: usize) -> Vec<Self> {
+				(0..n).map(|k| Self::from_polar(1., 2. * std::$t::consts::PI * k as $t / n as $t)).collect()
+			}
+			
+			/// Return the exponential `e^z`
+			pub fn exp(&self) -> Self {
+				Self::from_polar(self.real.exp(), self.imaginary)
+			}
+			
+			/// Return the principal natural logarithm
+			///
+			/// The branch cut is `(-∞, 0]`, continuous from above, so the imaginary part is in
+			/// `(-π, π]`. A negative zero imaginary part gives the value from below
+			pub fn ln(&self) -> Self {
+				Self {
+					real: self.abs().ln(),
+					imaginary: self.arg()
+				}
+			}
+			
+			/// Return the principal square root, which always has a non-negative real part
+			///
+			/// The branch cut is `(-∞, 0)`, continuous from above. A negative zero imaginary part
+			/// gives the value from below
+			/// ```
+			/// # use lineas::Complex;
+			/// assert_eq!(Complex::from_complex(-4f64, 0.).sqrt(), Complex::from_complex(0., 2.));
+			/// assert_eq!(Complex::from_complex(-4f64, -0.).sqrt(), Complex::from_complex(0., -2.))
+			/// ```
+			pub fn sqrt(&self) -> Self {
+				if self.real == 0. && self.imaginary == 0. {
+					return Self { real: 0., imaginary: self.imaginary }
+				}
+				// Pick the form without cancellation for the sign of the real part
+				if self.real >= 0. {
+					let t = ((self.abs() + self.real) / 2.).sqrt();
+					Self {
+						real: t,
+						imaginary: self.imaginary / (2. * t)
+					}
+				} else {
+					let t = ((self.abs() - self.real) / 2.).sqrt();
+					Self {
+						real: self.imaginary.abs() / (2. * t),
+						imaginary: t.copysign(self.imaginary)
+					}
+				}
+			}
+			
+			/// Raise the value to a real power `z^n`, using the principal logarithm
+			///
+			/// `0^n` is 0 for positive `n`, 1 for `n = 0`, and infinite for negative `n`
+			pub fn powf(&self, n: $t) -> Self {
+				if self.real == 0. && self.imaginary == 0. {
+					return Self { real: (0 as $t).powf(n), imaginary: 0. }
+				}
+				let (r, theta) = self.to_polar();
+				Self::from_polar(r.powf(n), theta * n)
+			}
+			
+			/// Raise the value to a complex power `z^w = e^(w·ln z)`, using the principal logarithm
+			///
+			/// `0^w` is 0 if `w` has a positive real part and 1 if `w = 0`. Any other power of 0 is
+			/// undefined and gives NaN
+			pub fn powc(&self, w: Self) -> Self {
+				if self.real == 0. && self.imaginary == 0. {
+					return if w.real == 0. && w.imaginary == 0. {
+						Self { real: 1., imaginary: 0. }
+					} else if w.real > 0. {
+						Self { real: 0., imaginary: 0. }
+					} else {
+						Self { real: $t::NAN, imaginary: $t::NAN }
+					}
+				}
+				(w * self.ln()).exp()
+			}
+			
+			/// Return the sine
+			pub fn sin(&self) -> Self {
+				Self {
+					real: self.real.sin() * self.imaginary.cosh(),
+					imaginary: self.real.cos() * self.imaginary.sinh()
+				}
+			}
+			
+			/// Return the cosine
+			pub fn cos(&self) -> Self {
+				Self {
+					real: self.real.cos() * self.imaginary.cosh(),
+					imaginary: -self.real.sin() * self.imaginary.sinh()
+				}
+			}
+			
+			/// Return the tangent
+			pub fn tan(&self) -> Self {
+				let (real, imaginary) = (2. * self.real, 2. * self.imaginary);
+				let denominator = real.cos() + imaginary.cosh();
+				Self {
+					real: real.sin() / denominator,
+					imaginary: imaginary.sinh() / denominator
+				}
+			}
+			
+			/// Return the hyperbolic sine
+			pub fn sinh(&self) -> Self {
+				Self {
+					real: self.real.sinh() * self.imaginary.cos(),
+					imaginary: self.real.cosh() * self.imaginary.sin()
+				}
+			}
+			
+			/// Return the hyperbolic cosine
+			pub fn cosh(&self) -> Self {
+				Self {
+					real: self.real.cosh() * self.imaginary.cos(),
+					imaginary: self.real.sinh() * self.imaginary.sin()
+				}
+			}
+			
+			/// Return the hyperbolic tangent
+			pub fn tanh(&self) -> Self {
+				let (real, imaginary) = (2. * self.real, 2. * self.imaginary);
+				let denominator = real.cosh() + imaginary.cos();
+				Self {
+					real: real.sinh() / denominator,
+					imaginary: imaginary.sin() / denominator
+				}
+			}
+			
+			/// Return the principal inverse sine
+			///
+			/// The real part is in `[-π/2, π/2]`. The branch cuts are `(-∞, -1)`, continuous from
+			/// above, and `(1, ∞)`, continuous from below
+			pub fn asin(&self) -> Self {
+				// -i·ln(iz + sqrt(1 - z²))
+				let one = Self { real: 1., imaginary: 0. };
+				let w = (self.times_i() + (one - *self * *self).sqrt()).ln();
+				Self { real: w.imaginary, imaginary: -w.real }
+			}
+			
+			/// Return the principal inverse cosine
+			///
+			/// The real part is in `[0, π]`. The branch cuts are `(-∞, -1)`, continuous from above,
+			/// and `(1, ∞)`, continuous from below
+			pub fn acos(&self) -> Self {
+				// -i·ln(z + i·sqrt(1 - z²))
+				let one = Self { real: 1., imaginary: 0. };
+				let w = (*self + (one - *self * *self).sqrt().times_i()).ln();
+				Self { real: w.imaginary, imaginary: -w.real }
+			}
+			
+			/// Return the principal inverse tangent
+			///
+			/// The real part is in `[-π/2, π/2]`. The branch cuts are `(-i∞, -i)`, continuous from
+			/// the left, and `(i, i∞)`, continuous from the right. The value is infinite at `±i`
+			pub fn atan(&self) -> Self {
+				// (ln(1 + iz) - ln(1 - iz)) / 2i
+				let one = Self { real: 1., imaginary: 0. };
+				let iz = self.times_i();
+				let w = (one + iz).ln() - (one - iz).ln();
+				Self { real: w.imaginary / 2., imaginary: -w.real / 2. }
+			}
+			
+			/// Return the principal inverse hyperbolic sine
+			///
+			/// The imaginary part is in `[-π/2, π/2]`. The branch cuts are `(-i∞, -i)`, continuous
+			/// from the left, and `(i, i∞)`, continuous from the right
+			pub fn asinh(&self) -> Self {
+				// ln(z + sqrt(1 + z²))
+				let one = Self { real: 1., imaginary: 0. };
+				(*self + (one + *self * *self).sqrt()).ln()
+			}
+			
+			/// Return the principal inverse hyperbolic cosine
+			///
+			/// The real part is non-negative and the imaginary part is in `[-π, π]`. The branch cut
+			/// is `(-∞, 1)`, continuous from above
+			pub fn acosh(&self) -> Self {
+				// 2·ln(sqrt((z + 1) / 2) + sqrt((z - 1) / 2))
+				let one = Self { real: 1., imaginary: 0. };
+				let half = Self { real: 0.5, imaginary: 0. };
+				let w = (((*self + one) * half).sqrt() + ((*self - one) * half).sqrt()).ln();
+				Self { real: 2. * w.real, imaginary: 2. * w.imaginary }
+			}
+			
+			/// Return the principal inverse hyperbolic tangent
+			///
+			/// The imaginary part is in `[-π/2, π/2]`. The branch cuts are `(-∞, -1]`, continuous
+			/// from above, and `[1, ∞)`, continuous from below. The value is infinite at `±1`
+			pub fn atanh(&self) -> Self {
+				// (ln(1 + z) - ln(1 - z)) / 2
+				let one = Self { real: 1., imaginary: 0. };
+				let w = (one + *self).ln() - (one - *self).ln();
+				Self { real: w.real / 2., imaginary: w.imaginary / 2. }
+			}
+			
+			/// Multiply by `i` without rounding or signed zero changes from a full multiplication
+			fn times_i(&self) -> Self {
+				Self { real: -self.imaginary, imaginary: self.real }
+			}
+		}
+	)*};
+}
+
+complex_functions!(f32, f64);
@@ -1,25 +1,26 @@
-use std::fmt::{Debug, Display, Error, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Sub, AddAssign, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
 use conv::{ConvUtil, ValueFrom};
 use crate::Complex;
+use crate::scalar::Zero;
 
-impl<L: Copy + Debug> Complex<L> where L: ValueFrom<isize> {
+impl<L: Copy + Debug> Complex<L> {
 	/// Make a new complex value from a real value
 	///
 	/// Generates a complex value with the imaginary part being 0
-	pub fn from_real(r: L) -> Self {
+	pub fn from_real(r: L) -> Self where L: Zero {
 		Self {
 			real: r,
-			imaginary: 0.value_as().unwrap()
+			imaginary: L::zero()
 		}
 	}
 	
 	/// Make a new complex value from an imaginary value
 	///
 	/// Generates a complex value with the real part being 0
-	pub fn from_imaginary(i: L) -> Self {
+	pub fn from_imaginary(i: L) -> Self where L: Zero {
 		Self {
-			real: 0.value_as().unwrap(),
+			real: L::zero(),
 			imaginary: i
 		}
 	}
@@ -54,19 +55,29 @@ impl<L: Copy + Debug> Complex<L> where L: ValueFrom<isize> {
 	}
 	
 	/// Changes the data type of the values
+	///
+	/// This will panic if either part can't be represented in the new dtype
 	pub fn dtype<Q>(&self) -> Complex<Q> where Q: Copy + Debug + ValueFrom<L> {
-		Complex {
-			real: self.real.value_as::<Q>().unwrap(),
-			imaginary: self.imaginary.value_as::<Q>().unwrap()
-		}
+		self.try_dtype().expect("Values could not be converted to the new dtype")
+	}
+	
+	/// Changes the data type of the values
+	///
+	/// Returns [`Error::ConversionOverflow`][crate::Error::ConversionOverflow] if either part can't
+	/// be represented in the new dtype
+	pub fn try_dtype<Q>(&self) -> Result<Complex<Q>, crate::Error> where Q: Copy + Debug + ValueFrom<L> {
+		Ok(Complex {
+			real: self.real.value_as::<Q>().map_err(|_| crate::Error::ConversionOverflow)?,
+			imaginary: self.imaginary.value_as::<Q>().map_err(|_| crate::Error::ConversionOverflow)?
+		})
 	}
 }
 
-impl<L: Copy + Debug> ValueFrom<isize> for Complex<L> where L: ValueFrom<isize> {
-	type Err = Error;
-
+impl<L: Copy + Debug> ValueFrom<isize> for Complex<L> where L: ValueFrom<isize> + Zero {
+	type Err = L::Err;
+	
 	fn value_from(src: isize) -> Result<Self, Self::Err> {
-		Ok(Complex::from_real(src.value_as().unwrap()))
+		Ok(Complex::from_real(L::value_from(src)?))
 	}
 }
 
@@ -106,6 +117,17 @@ impl<L: Copy + Debug> SubAssign<Complex<L>> for Complex<L> where L: Sub<Output=L
 	}
 }
 
+impl<L: Copy + Debug> Neg for Complex<L> where L: Neg<Output=L> {
+	type Output = Self;
+	
+	fn neg(self) -> Self::Output {
+		Self {
+			real: -self.real,
+			imaginary: -self.imaginary
+		}
+	}
+}
+
 impl<L: Copy + Debug> Mul<Complex<L>> for Complex<L> where L: Mul<Output=L> + Sub<Output=L> + Add<Output=L> {
 	type Output = Self;
 	
@@ -125,14 +147,14 @@ impl<L: Copy + Debug> MulAssign<Complex<L>> for Complex<L> where L: Mul<Output=L
 	}
 }
 
-impl<L: Copy + Debug> Div<Complex<L>> for Complex<L> where L: Mul<Output=L> + Div<Output=L> + Sub<Output=L> + Add<Output=L> + ValueFrom<isize> + Neg<Output=L> {
+impl<L: Copy + Debug> Div<Complex<L>> for Complex<L> where L: Mul<Output=L> + Div<Output=L> + Sub<Output=L> + Add<Output=L> + Neg<Output=L> {
 	type Output = Self;
 	
 	/// Divide two complex numbers.
 	///
 	/// Be aware that this may panic if for `a/b`, `b==0`
 	fn div(self, rhs: Complex<L>) -> Self::Output {
-		let mut top = self.clone();
+		let mut top = self;
 		top *= rhs.conj();
 		let bottom = rhs.real * rhs.real + rhs.imaginary * rhs.imaginary;
 		top.real = top.real / bottom;
@@ -141,12 +163,12 @@ impl<L: Copy + Debug> Div<Complex<L>> for Complex<L> where L: Mul<Output=L> + Di
 	}
 }
 
-impl<L: Copy + Debug> DivAssign<Complex<L>> for Complex<L> where L: Mul<Output=L> + Div<Output=L> + Sub<Output=L> + Add<Output=L> + ValueFrom<isize> + Neg<Output=L> {
+impl<L: Copy + Debug> DivAssign<Complex<L>> for Complex<L> where L: Mul<Output=L> + Div<Output=L> + Sub<Output=L> + Add<Output=L> + Neg<Output=L> {
 	/// Divide two complex numbers.
 	///
 	/// Be aware that this may panic if for `a/b`, `b==0`
 	fn div_assign(&mut self, rhs: Complex<L>) {
-		let mut top = self.clone();
+		let mut top = *self;
 		top *= rhs.conj();
 		let bottom = rhs.real * rhs.real + rhs.imaginary * rhs.imaginary;
 		top.real = top.real / bottom;
@@ -158,6 +180,9 @@ impl<L: Copy + Debug> DivAssign<Complex<L>> for Complex<L> where L: Mul<Output=L
 
 impl<L: Display + Debug + Copy> Display for Complex<L> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		write!(f, "{}{:+}i", self.real, self.imaginary)
+		match f.precision() {
+			Some(e) => write!(f, "{:.e$}{:+.e$}i", self.real, self.imaginary),
+			None => write!(f, "{}{:+}i", self.real, self.imaginary)
+		}
 	}
 }
@@ -6,13 +6,17 @@
 //! For example to include LU decomposition, you would have
 //!```
 //! # use lineas::prelude;
-//! use lineas::decompose::lu_decompose;
+//! use lineas::decompose::LUDecompose;
 //!```
 
 use std::fmt::Debug;
 use std::ops::{Div, Mul, Sub};
-use conv::{ConvUtil, ValueFrom};
-use crate::prelude::Matrix;
+use crate::prelude::{BoxedMatrix, Complex, DMatrix, Matrix, Vector};
+use crate::dense::Dense;
+use crate::Error;
+use crate::eigen;
+use crate::vectors::real;
+use crate::scalar::{assert_normed, ComplexField, Field, One, RealField, Scalar, Zero};
 use itertools::Itertools;
 
 /// LU decomposition trait
@@ -23,13 +27,13 @@ pub trait LUDecompose {
 	///
 	/// The LU decomposition of a matrix `A` is two matrices `L` and `U` that are lower and
 	/// upper matrices respectively such that `LU=A`. For some matrices this decomposition is
-	/// not possible so we return an `Option<(Self, Self)>` enum.
+	/// not possible, in which case [`Error::ZeroPivot`] gives the first zero pivot.
 	///
 	/// For example
 	/// ```
 	/// # use lineas::Matrix;
 	/// # #[allow(non_snake_case)]
-	/// let A = Matrix::new([[2, -2], [-12, 16]]);
+	/// let A = Matrix::new([[4, -2], [-12, 16]]);
 	/// # #[allow(non_snake_case)]
 	/// let L = Matrix::new([[1, 0], [-3, 2]]);
 	/// # #[allow(non_snake_case)]
@@ -38,32 +42,39 @@ pub trait LUDecompose {
 	/// ```
 	///
 	/// > We recommend using floats for decomposition
-	fn lu_decompose(&self) -> Option<(Self, Self)> where Self: Sized;
+	fn lu_decompose(&self) -> Result<(Self, Self), Error> where Self: Sized;
 }
 
-impl<const T: usize, L: Copy + Debug> LUDecompose for Matrix<T, T, L> where L: ValueFrom<isize> + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
-	fn lu_decompose(&self) -> Option<(Self, Self)> {
-		let mut upper = self.clone();
-		let mut lower = Self::empty();
-		for r in 0..T {
-			let scale = upper[(r, r)];
-			if scale == 0.value_as().unwrap() {
-				return None
-			}
-			for i in r..T {
-				lower[(i, r)] = upper[(i, r)]
-			}
-			for i in 0..T {
-				upper[(r, i)] = upper[(r, i)] / scale
-			}
-			for lower in r + 1..T {
-				let second_scale = upper[(lower, r)];
-				for col in r..T {
-					upper[(lower, col)] = upper[(lower, col)] - second_scale * upper[(r, col)]
-				}
-			}
+/// LU decomposition without pivoting, which fails if any pivot is zero
+fn lu<L, M: Dense<L>>(matrix: &M) -> Result<(M, M), Error> where L: Copy + Debug + Zero + One + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
+	let mut upper = matrix.clone();
+	let mut lower = matrix.zeros_like();
+	for r in 0..matrix.shape().0 {
+		if upper[(r, r)] == L::zero() {
+			return Err(Error::ZeroPivot { row: r, col: r })
 		}
-		Some((lower, upper))
+		eliminate(&mut upper, &mut lower, r);
+	}
+	Ok((lower, upper))
+}
+
+impl<const T: usize, L: Copy + Debug> LUDecompose for Matrix<T, T, L> where L: Zero + One + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
+	fn lu_decompose(&self) -> Result<(Self, Self), Error> {
+		lu(self)
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> LUDecompose for BoxedMatrix<T, T, L> where L: Zero + One + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
+	fn lu_decompose(&self) -> Result<(Self, Self), Error> {
+		lu(self)
+	}
+}
+
+impl<L: Copy + Debug> LUDecompose for DMatrix<L> where L: Zero + One + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
+	/// Returns [`Error::DimensionMismatch`] if the matrix is not square
+	fn lu_decompose(&self) -> Result<(Self, Self), Error> {
+		square(self)?;
+		lu(self)
 	}
 }
 
@@ -77,44 +88,986 @@ pub trait PLUDecompose {
 	/// permutation matrix. If a PLU decomposition exists for a matrix `A` that means that the
 	/// product `P * L * U == A`.
 	///
-	/// This can fail when no permutation has an LU decomposition.
+	/// This uses Gaussian elimination with partial pivoting, so the rows are reordered to put the
+	/// largest value in each column on the diagonal. This takes `O(T³)` operations.
+	///
+	/// This returns [`Error::SingularMatrix`] for singular matrices, which have no non-zero pivot
+	/// in some column. For integer dtypes `L` and `U` are only integral if each pivot divides its
+	/// row, and [`Error::InexactDivision`] is returned otherwise.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::PLUDecompose;
+	/// let a = Matrix::new([[1, 2, 2], [4, 4, 2], [4, 6, 4]]).dtype::<f64>();
+	/// let (p, l, u) = a.plu_decompose().unwrap();
+	/// assert_eq!(p * l * u, a)
+	/// ```
+	///
+	/// > We recommend using floats for decomposition
+	fn plu_decompose(&self) -> Result<(Self, Self, Self), Error> where Self: Sized;
+}
+
+/// PLUQ decomposition trait
+///
+/// This is implemented for square matrices
+pub trait PLUQDecompose {
+	/// Attempts to generate a PLUQ decomposition for a square matrix
+	///
+	/// This is the same as [`plu_decompose`][PLUDecompose::plu_decompose] but uses complete
+	/// pivoting, so the columns are reordered as well as the rows. The largest remaining value in
+	/// the matrix is used as each pivot. This returns `(P, L, U, Q)` such that
+	/// `P * L * U * Q == A` where `P` and `Q` are permutation matrices.
+	///
+	/// Complete pivoting is more numerically stable than partial pivoting but is slower.
 	///
 	/// > We recommend using floats for decomposition
-	fn plu_decompose(&self) -> Option<(Self, Self, Self)> where Self: Sized;
+	fn pluq_decompose(&self) -> Result<(Self, Self, Self, Self), Error> where Self: Sized;
+}
+
+/// Check a runtime sized matrix is square, returning [`Error::DimensionMismatch`] if not
+fn square<L: Copy + Debug>(matrix: &DMatrix<L>) -> Result<(), Error> {
+	if matrix.rows != matrix.cols {
+		return Err(Error::DimensionMismatch { expected: (matrix.rows, matrix.rows), found: matrix.shape() })
+	}
+	Ok(())
+}
+
+/// Eliminate the column `r` below the diagonal, storing the pivot column in `lower` and scaling
+/// row `r` of `upper` to have a 1 on the diagonal
+fn eliminate<L: Copy + Debug + Div<Output=L> + Mul<Output=L> + Sub<Output=L>, M: Dense<L>>(upper: &mut M, lower: &mut M, r: usize) {
+	let size = upper.shape().0;
+	let scale = upper[(r, r)];
+	for i in r..size {
+		lower[(i, r)] = upper[(i, r)]
+	}
+	for i in 0..size {
+		upper[(r, i)] = upper[(r, i)] / scale
+	}
+	for row in r + 1..size {
+		let second_scale = upper[(row, r)];
+		for col in r..size {
+			upper[(row, col)] = upper[(row, col)] - second_scale * upper[(r, col)]
+		}
+	}
+}
+
+/// Check that row `r` of `upper` divides exactly by its pivot, returning
+/// [`Error::InexactDivision`] if not. This is always true for fields, but integer dtypes would
+/// otherwise be truncated by [`eliminate`]
+fn exact_pivot<L: Scalar, M: Dense<L>>(upper: &M, r: usize) -> Result<(), Error> {
+	let scale = upper[(r, r)];
+	if L::FIELD || (0..upper.shape().0).all(|i| upper[(r, i)] / scale * scale == upper[(r, i)]) {
+		Ok(())
+	} else {
+		Err(Error::InexactDivision)
+	}
+}
+
+/// Generate a permutation matrix with a 1 at `(n, order[n])` for each row `n`, with the same size
+/// as `like`
+fn permutation<L: Copy + Debug + Zero + One, M: Dense<L>>(order: &[usize], like: &M) -> M {
+	let mut out = like.zeros_like();
+	for (n, j) in order.iter().enumerate() {
+		out[(n, *j)] = L::one()
+	}
+	out
+}
+
+/// Gaussian elimination with partial pivoting. Returns the original row index of each row of `LU`
+/// with `L` and `U`, or [`Error::SingularMatrix`] if any pivot is no larger than `tolerance`.
+/// Integer dtypes return [`Error::InexactDivision`] if a pivot doesn't divide its row
+fn partial_pivot<L: Scalar, M: Dense<L>>(matrix: &M, tolerance: f64) -> Result<(Vec<usize>, M, M), Error> {
+	let size = matrix.shape().0;
+	let mut upper = matrix.clone();
+	let mut lower = matrix.zeros_like();
+	let mut rows = (0..size).collect::<Vec<usize>>();
+	for r in 0..size {
+		let pivot = (r + 1..size).fold(r, |a, b| if upper[(b, r)].modulus() > upper[(a, r)].modulus() { b } else { a });
+		if upper[(pivot, r)].modulus() <= tolerance {
+			return Err(Error::SingularMatrix)
+		}
+		upper.swap_rows(r, pivot);
+		lower.swap_rows(r, pivot);
+		rows.swap(r, pivot);
+		exact_pivot(&upper, r)?;
+		eliminate(&mut upper, &mut lower, r);
+	}
+	Ok((rows, lower, upper))
+}
+
+/// Gaussian elimination with complete pivoting. Returns `(P, L, U, Q)`, or
+/// [`Error::SingularMatrix`] if the matrix is singular
+fn complete_pivot<L: Scalar, M: Dense<L>>(matrix: &M) -> Result<(M, M, M, M), Error> {
+	let size = matrix.shape().0;
+	let mut upper = matrix.clone();
+	let mut lower = matrix.zeros_like();
+	let mut rows = (0..size).collect::<Vec<usize>>();
+	let mut cols = rows.clone();
+	for r in 0..size {
+		let (pivot_row, pivot_col) = (r..size).cartesian_product(r..size)
+			.fold((r, r), |a, b| if upper[b].modulus() > upper[a].modulus() { b } else { a });
+		if upper[(pivot_row, pivot_col)] == L::zero() {
+			return Err(Error::SingularMatrix)
+		}
+		upper.swap_rows(r, pivot_row);
+		lower.swap_rows(r, pivot_row);
+		rows.swap(r, pivot_row);
+		upper.swap_cols(r, pivot_col);
+		cols.swap(r, pivot_col);
+		exact_pivot(&upper, r)?;
+		eliminate(&mut upper, &mut lower, r);
+	}
+	let mut p = matrix.zeros_like();
+	for (n, i) in rows.iter().enumerate() {
+		p[(*i, n)] = L::one()
+	}
+	Ok((p, lower, upper, permutation(&cols, matrix)))
+}
+
+impl<const T: usize, L: Scalar> PLUDecompose for Matrix<T, T, L> {
+	fn plu_decompose(&self) -> Result<(Self, Self, Self), Error> {
+		let (rows, lower, upper) = partial_pivot(self, 0.)?;
+		Ok((permutation(&rows, self).transpose(), lower, upper))
+	}
+}
+
+impl<const T: usize, L: Scalar> PLUQDecompose for Matrix<T, T, L> {
+	fn pluq_decompose(&self) -> Result<(Self, Self, Self, Self), Error> {
+		complete_pivot(self)
+	}
+}
+
+impl<const T: usize, L: Scalar> PLUDecompose for BoxedMatrix<T, T, L> {
+	fn plu_decompose(&self) -> Result<(Self, Self, Self), Error> {
+		let (rows, lower, upper) = partial_pivot(self, 0.)?;
+		Ok((permutation(&rows, self).transpose(), lower, upper))
+	}
+}
+
+impl<const T: usize, L: Scalar> PLUQDecompose for BoxedMatrix<T, T, L> {
+	fn pluq_decompose(&self) -> Result<(Self, Self, Self, Self), Error> {
+		complete_pivot(self)
+	}
+}
+
+impl<L: Scalar> PLUDecompose for DMatrix<L> {
+	/// Returns [`Error::DimensionMismatch`] if the matrix is not square
+	fn plu_decompose(&self) -> Result<(Self, Self, Self), Error> {
+		square(self)?;
+		let (rows, lower, upper) = partial_pivot(self, 0.)?;
+		Ok((permutation(&rows, self).transpose(), lower, upper))
+	}
+}
+
+impl<L: Scalar> PLUQDecompose for DMatrix<L> {
+	/// Returns [`Error::DimensionMismatch`] if the matrix is not square
+	fn pluq_decompose(&self) -> Result<(Self, Self, Self, Self), Error> {
+		square(self)?;
+		complete_pivot(self)
+	}
+}
+
+/// Reusable PLU factorisation of a square matrix
+///
+/// This stores the [PLU decomposition][PLUDecompose] of a matrix `A` so that it can be used to
+/// solve `AX = B` for many different `B` without decomposing `A` each time. Each solve uses forward
+/// and back substitution and takes `O(T²)` operations per column of `B`.
+///
+/// Substitution divides by the pivots, which would truncate for integer dtypes, so they can't be
+/// factorised. Use [`Matrix::solve`] instead, which is exact for integer dtypes.
+/// ```
+/// # use lineas::Matrix;
+/// use lineas::decompose::PLUFactorisation;
+/// let a = Matrix::new([[2, 1], [4, 3]]).dtype::<f64>();
+/// let factorisation = PLUFactorisation::new(&a).unwrap();
+/// assert_eq!(factorisation.solve(&Matrix::new([[3.], [7.]])), Matrix::new([[1.], [1.]]));
+/// assert_eq!(factorisation.solve(&Matrix::new([[1.], [1.]])), Matrix::new([[1.], [-1.]]))
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PLUFactorisation<const T: usize, L: Copy + Debug> {
+	/// Original row index of each row of `LU`
+	rows: [usize; T],
+	lower: Matrix<T, T, L>,
+	upper: Matrix<T, T, L>,
+	/// 1-norm of `A`, which is needed to estimate its condition number
+	norm: f64
 }
 
-impl<const T: usize, L: Copy + Debug> PLUDecompose for  Matrix<T, T, L> where L: ValueFrom<isize> + Div<Output=L> + Mul<Output=L> + Sub<Output=L> + PartialEq {
-	fn plu_decompose(&self) -> Option<(Self, Self, Self)> {
-		for i in (0..T).permutations(T) {
-			let mut p_data = [[0.value_as::<L>().unwrap(); T]; T];
-			for (n, j) in i.iter().enumerate() {
-				p_data[n] = self.0[*j].clone()
+impl<const T: usize, L: Scalar> PLUFactorisation<T, L> {
+	/// Factorise a square matrix
+	///
+	/// The matrix is treated as singular if any pivot is no larger than `T * ε` times the largest
+	/// value in the matrix, where `ε` is the [machine epsilon][Scalar::EPSILON] of the dtype.
+	/// Returns [`Error::InexactDivision`] for integer dtypes.
+	pub fn new(matrix: &Matrix<T, T, L>) -> Result<Self, Error> {
+		Self::with_tolerance(matrix, T as f64 * L::EPSILON)
+	}
+	
+	/// Factorise a square matrix using a given pivot tolerance
+	///
+	/// The matrix is treated as singular if any pivot is no larger than `tolerance` times the
+	/// largest value in the matrix.
+	pub fn with_tolerance(matrix: &Matrix<T, T, L>, tolerance: f64) -> Result<Self, Error> {
+		if !L::FIELD {
+			return Err(Error::InexactDivision)
+		}
+		let (rows, lower, upper) = partial_pivot(matrix, tolerance * matrix.max_abs())?;
+		Ok(Self { rows: std::array::from_fn(|i| rows[i]), lower, upper, norm: matrix.max_column_sum() })
+	}
+	
+	/// Return the permutation matrix `P`
+	pub fn permutation(&self) -> Matrix<T, T, L> {
+		permutation(&self.rows, &self.lower).transpose()
+	}
+	
+	/// Return the lower triangular matrix `L`
+	pub fn lower(&self) -> Matrix<T, T, L> {
+		self.lower
+	}
+	
+	/// Return the upper triangular matrix `U`
+	pub fn upper(&self) -> Matrix<T, T, L> {
+		self.upper
+	}
+	
+	/// Solve `AX = B` for `X`
+	///
+	/// Each column of `B` is a separate right hand side
+	pub fn solve<const K: usize>(&self, b: &Matrix<T, K, L>) -> Matrix<T, K, L> {
+		let mut out = Matrix::new(self.rows.map(|r| b.0[r]));
+		for c in 0..K {
+			for r in 0..T {
+				let mut value = out[(r, c)];
+				for k in 0..r {
+					value -= self.lower[(r, k)] * out[(k, c)]
+				}
+				out[(r, c)] = value / self.lower[(r, r)];
+			}
+			for r in (0..T).rev() {
+				let mut value = out[(r, c)];
+				for k in r + 1..T {
+					value -= self.upper[(r, k)] * out[(k, c)]
+				}
+				out[(r, c)] = value;
+			}
+		}
+		out
+	}
+	
+	/// Solve `Ax = b` for `x` where `b` is a vector
+	pub fn solve_vector(&self, b: &Vector<T, L>) -> Vector<T, L> {
+		self.solve(&b.transpose()).transpose()
+	}
+	
+	/// Solve `AᵀX = B` for `X`
+	///
+	/// For complex dtypes this solves `AᴴX = B` with the conjugate transpose instead. Each column
+	/// of `B` is a separate right hand side.
+	pub fn solve_transpose<const K: usize>(&self, b: &Matrix<T, K, L>) -> Matrix<T, K, L> {
+		let mut solved = *b;
+		for c in 0..K {
+			for r in 0..T {
+				let mut value = solved[(r, c)];
+				for k in 0..r {
+					value -= self.upper[(k, r)].conj() * solved[(k, c)]
+				}
+				solved[(r, c)] = value;
 			}
-			if let Some((l, u)) = Matrix::new(p_data).lu_decompose() {
-				let mut permutation = Matrix::<T, T, L>::empty();
-				for (n, j) in i.iter().enumerate() {
-					permutation.0[n][*j] = 1.value_as().unwrap()
+			for r in (0..T).rev() {
+				let mut value = solved[(r, c)];
+				for k in r + 1..T {
+					value -= self.lower[(k, r)].conj() * solved[(k, c)]
 				}
-				return Some((permutation, l, u))
+				solved[(r, c)] = value / self.lower[(r, r)].conj();
+			}
+		}
+		let mut out = solved;
+		for (r, row) in self.rows.iter().enumerate() {
+			out.0[*row] = solved.0[r]
+		}
+		out
+	}
+	
+	/// Estimate the condition number `‖A‖₁‖A⁻¹‖₁` of the factorised matrix in the 1-norm
+	///
+	/// See [`Matrix::condition_number`] for what the condition number measures. Calculating `‖A⁻¹‖₁`
+	/// exactly needs the inverse, which takes `O(T³)` operations. This uses Hager's method with
+	/// Higham's improvements instead, which only needs a few solves with `A` and `Aᵀ` using this
+	/// factorisation so takes `O(T²)` operations. The estimate is never larger than the true
+	/// condition number and is almost always within a factor of three of it.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::PLUFactorisation;
+	/// let a = Matrix::new([[1., 1.], [1., 1.0001]]);
+	/// let estimate = PLUFactorisation::new(&a).unwrap().condition_estimate();
+	/// assert!((estimate - 40004.).abs() < 1e-6 * 40004.)
+	/// ```
+	pub fn condition_estimate(&self) -> f64 {
+		assert_normed::<L>();
+		if T == 0 {
+			return 0.
+		}
+		let norm = |m: &Matrix<T, 1, L>| m.0.iter().map(|row| row[0].modulus()).sum::<f64>();
+		let mut x = Matrix::<T, 1, L>::new([[real(1. / T as f64)]; T]);
+		let mut estimate = 0.;
+		let mut previous = None;
+		for _ in 0..5 {
+			let y = self.solve(&x);
+			if norm(&y) <= estimate {
+				break
+			}
+			estimate = norm(&y);
+			let sign = y.0.map(|[v]| [if v.modulus() == 0. { real(1.) } else { v * real(1. / v.modulus()) }]);
+			let z = self.solve_transpose(&Matrix::new(sign));
+			let j = (1..T).fold(0, |a, b| if z[(b, 0)].modulus() > z[(a, 0)].modulus() { b } else { a });
+			let product = (0..T).fold(Complex::from_real(0.), |a, i| a + (z[(i, 0)].conj() * x[(i, 0)]).to_complex());
+			if previous == Some(j) || z[(j, 0)].modulus() <= product.real {
+				break
 			}
+			x = Matrix::empty();
+			x[(j, 0)] = real(1.);
+			previous = Some(j);
 		}
-		None
+		if T > 1 {
+			let alternating = std::array::from_fn(|i| [real(if i % 2 == 0 { 1. } else { -1. } * (1. + i as f64 / (T - 1) as f64))]);
+			estimate = estimate.max(2. * norm(&self.solve(&Matrix::new(alternating))) / (3. * T as f64));
+		}
+		estimate * self.norm
+	}
+	
+	/// Return the inverse of the factorised matrix
+	pub fn inverse(&self) -> Matrix<T, T, L> {
+		self.solve(&Matrix::identity())
+	}
+}
+
+impl<const T: usize, L: Scalar> Matrix<T, T, L> {
+	/// Return the reusable [PLU factorisation][PLUFactorisation] of the matrix
+	///
+	/// Returns [`Error::SingularMatrix`] if the matrix is singular, and
+	/// [`Error::InexactDivision`] for integer dtypes
+	pub fn plu_factorise(&self) -> Result<PLUFactorisation<T, L>, Error> {
+		PLUFactorisation::new(self)
+	}
+	
+	/// Solve `AX = B` for `X`
+	///
+	/// Each column of `B` is a separate right hand side. Returns [`Error::SingularMatrix`] if `A`
+	/// is singular. If you need to solve for multiple `B` with the same `A`, use
+	/// [`plu_factorise`][Matrix::plu_factorise] and reuse the factorisation.
+	///
+	/// For integer dtypes this uses fraction-free elimination so every value stays exact, and
+	/// returns [`Error::InexactDivision`] if `X` has non-integer values.
+	/// ```
+	/// # use lineas::{Error, Matrix};
+	/// let a = Matrix::new([[1, 1], [1, -1]]).dtype::<f64>();
+	/// let b = Matrix::new([[3., 5.], [1., 1.]]);
+	/// assert_eq!(a.solve(&b), Ok(Matrix::new([[2., 3.], [1., 2.]])));
+	/// let a = Matrix::new([[2, 1], [1, 3]]);
+	/// assert_eq!(a.solve(&Matrix::new([[3], [4]])), Ok(Matrix::new([[1], [1]])));
+	/// assert_eq!(a.solve(&Matrix::new([[1], [1]])), Err(Error::InexactDivision))
+	/// ```
+	pub fn solve<const K: usize>(&self, b: &Matrix<T, K, L>) -> Result<Matrix<T, K, L>, Error> {
+		if L::FIELD {
+			Ok(self.plu_factorise()?.solve(b))
+		} else {
+			self.bareiss_solve(b)
+		}
+	}
+	
+	/// Solve `Ax = b` for `x` where `b` is a vector
+	///
+	/// This is the same as [`solve`][Matrix::solve] with a single right hand side
+	pub fn solve_vector(&self, b: &Vector<T, L>) -> Result<Vector<T, L>, Error> {
+		Ok(self.solve(&b.transpose())?.transpose())
 	}
 }
 
 /// Diagonalisation decomposition
 ///
-/// Implemented for square matrices
+/// Implemented for square matrices. The eigenvectors are calculated with `Complex<f64>` values and
+/// converted back to the dtype, so for integer dtypes this only succeeds when every value of `P`,
+/// `D` and `P'` is an integer. Eigenvectors are normalised to length one so this is rare, and most
+/// integer matrices give `None`. Convert to `f64` or `Complex<f64>` first with
+/// [`dtype`][Matrix::dtype].
 #[doc(alias="Diagonalize")]
 pub trait Diagonalise {
 	/// Diagonalisation a matrix
 	///
 	/// This means that for a matrix `A`, we have two matrices `P` and `D` such that `PDP'==A` where
-	/// `P'` is the inverse of `P`.
+	/// `P'` is the inverse of `P`. This returns `(P, D, P')`.
+	///
+	/// The columns of `P` are the eigenvectors of `A` and the diagonal of `D` are the matching
+	/// eigenvalues in ascending order of their real part. This fails if `A` is defective, meaning it
+	/// does not have enough linearly independent eigenvectors. It will also fail for real matrices
+	/// with complex eigenvalues, and for almost all integer matrices, because `P` and `D` can't be
+	/// represented in the same dtype.
 	///
 	/// The process is linked to finding eigenvalues and so can be inaccurate due to the nature of
-	/// eigenvalue calculation.
+	/// eigenvalue calculation. Eigenvalues closer together than `1e-6` times the largest value in
+	/// the matrix are treated as repeated. To change this, use
+	/// [`diagonalise_with_tolerance`][Diagonalise::diagonalise_with_tolerance].
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::Diagonalise;
+	/// let a = Matrix::new([[4, 1], [2, 3]]).dtype::<f64>();
+	/// let (p, d, p_inv) = a.diagonalise().unwrap();
+	/// assert!((d[(0, 0)] - 2.).abs() < 1e-12 && (d[(1, 1)] - 5.).abs() < 1e-12);
+	/// # let check = p * d * p_inv - a;
+	/// # assert!((0..2).all(|r| (0..2).all(|c| check[(r, c)].abs() < 1e-12)))
+	/// ```
 	///
 	/// > We recommend using floats for decomposition
 	fn diagonalise(&self) -> Option<(Self, Self, Self)> where Self: Sized;
+	
+	/// Diagonalise a matrix with a given tolerance
+	///
+	/// This is the same as [`diagonalise`][Diagonalise::diagonalise] but with a custom tolerance
+	/// relative to the largest value in the matrix. Eigenvalues closer together than this are
+	/// treated as repeated, and pivots smaller than this are treated as zero when finding
+	/// eigenvectors or inverting `P`.
+	fn diagonalise_with_tolerance(&self, tolerance: f64) -> Option<(Self, Self, Self)> where Self: Sized;
+}
+
+impl<const T: usize, L: Scalar> Diagonalise for Matrix<T, T, L> {
+	fn diagonalise(&self) -> Option<(Self, Self, Self)> {
+		self.diagonalise_with_tolerance(1e-6)
+	}
+	
+	fn diagonalise_with_tolerance(&self, tolerance: f64) -> Option<(Self, Self, Self)> {
+		let a = self.0.map(|row| row.map(|v| v.to_complex()));
+		let scale = self.max_abs();
+		let relative = tolerance;
+		let tolerance = relative * if scale == 0. { 1. } else { scale };
+		let values = eigen::eigenvalues(a)?
+			.iter().map(|v| L::from_complex(*v, tolerance).map(|v| v.to_complex()))
+			.collect::<Option<Vec<Complex<f64>>>>()?;
+		let mut vectors = Vec::with_capacity(T);
+		let mut diagonal = Vec::with_capacity(T);
+		for (value, multiplicity, basis) in eigen::eigenspaces(a, values, tolerance) {
+			if basis.len() != multiplicity {
+				return None
+			}
+			vectors.extend(basis);
+			diagonal.extend((0..multiplicity).map(|_| value));
+		}
+		let mut p = [[Complex::from_real(0.); T]; T];
+		let mut d = p;
+		for (c, vector) in vectors.iter().enumerate() {
+			for r in 0..T {
+				p[r][c] = vector[r]
+			}
+			d[c][c] = diagonal[c];
+		}
+		let p_scale = p.iter().flatten().map(|v| v.abs()).fold(0., f64::max);
+		let p_inverse = eigen::inverse(p, relative * p_scale)?;
+		let convert = |m: [[Complex<f64>; T]; T]| -> Option<Self> {
+			let mut out = Self::empty();
+			for r in 0..T {
+				for c in 0..T {
+					out[(r, c)] = L::from_complex(m[r][c], tolerance)?
+				}
+			}
+			Some(out)
+		};
+		Some((convert(p)?, convert(d)?, convert(p_inverse)?))
+	}
+}
+
+/// QR decomposition trait
+///
+/// This is implemented for rectangular matrices of any shape with a [`Field`] dtype. Integer
+/// dtypes aren't supported since the square roots and divisions would be truncated.
+pub trait QRDecompose<const T: usize, const N: usize, L: Copy + Debug> {
+	/// Generate the full QR decomposition of a matrix
+	///
+	/// The QR decomposition of a `T`×`N` matrix `A` is a `T`×`T` orthogonal matrix `Q` and a
+	/// `T`×`N` upper triangular matrix `R` such that `QR=A`. For complex dtypes `Q` is unitary
+	/// instead. The diagonal of `R` is real and non-negative.
+	///
+	/// This uses Householder reflections which are numerically stable.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::QRDecompose;
+	/// let a = Matrix::new([[3, 0], [4, 5], [0, 4]]).dtype::<f64>();
+	/// let (q, r) = a.qr_decompose();
+	/// let check = r - Matrix::new([[5., 4.], [0., 5.], [0., 0.]]);
+	/// assert!((0..3).all(|i| (0..2).all(|j| check[(i, j)].abs() < 1e-14)));
+	/// # let check = q * r - a;
+	/// # assert!((0..3).all(|i| (0..2).all(|j| check[(i, j)].abs() < 1e-14)))
+	/// ```
+	fn qr_decompose(&self) -> (Matrix<T, T, L>, Matrix<T, N, L>);
+	
+	/// Generate the thin QR decomposition of a matrix
+	///
+	/// For a `T`×`N` matrix `A` with `T≥N`, this is a `T`×`N` matrix `Q` with orthonormal columns
+	/// and an `N`×`N` upper triangular matrix `R` such that `QR=A`. These are the first `N` columns
+	/// of `Q` and the first `N` rows of `R` from the [full decomposition][QRDecompose::qr_decompose].
+	///
+	/// This will fail to compile if `T<N`, in which case the full decomposition is already thin.
+	fn thin_qr_decompose(&self) -> (Matrix<T, N, L>, Matrix<N, N, L>);
+	
+	/// Generate the thin QR decomposition of a matrix using modified Gram–Schmidt
+	///
+	/// This gives the same result as [`thin_qr_decompose`][QRDecompose::thin_qr_decompose] but
+	/// by orthogonalising the columns of `A` one at a time. It is cheaper than Householder
+	/// reflections but `Q` can lose orthogonality for badly conditioned matrices.
+	///
+	/// This returns [`Error::SingularMatrix`] if the columns of `A` are linearly dependent, so
+	/// `T≥N` is needed for it to succeed.
+	fn gram_schmidt_qr_decompose(&self) -> Result<(Matrix<T, N, L>, Matrix<N, N, L>), Error>;
+}
+
+/// Householder QR decomposition of `r`, where `q` is the identity matrix with as many rows as `r`
+fn householder_qr<L: Field, Q: Dense<L>, R: Dense<L>>(mut q: Q, mut r: R) -> (Q, R) {
+	assert_normed::<L>();
+	let zero = L::zero();
+	let one = L::one();
+	let two = L::from_usize(2);
+	let (rows, cols) = r.shape();
+	for k in 0..rows.min(cols) {
+		let norm = (k..rows).fold(zero, |a, i| a + r[(i, k)].conj() * r[(i, k)]).sqrt();
+		if norm == zero {
+			continue
+		}
+		let phase = if r[(k, k)] == zero { one } else { r[(k, k)] / (r[(k, k)].conj() * r[(k, k)]).sqrt() };
+		let mut v = vec![zero; rows];
+		for i in k..rows {
+			v[i] = r[(i, k)]
+		}
+		v[k] += phase * norm;
+		let scale = two / (k..rows).fold(zero, |a, i| a + v[i].conj() * v[i]);
+		for j in 0..cols {
+			let s = (k..rows).fold(zero, |a, i| a + v[i].conj() * r[(i, j)]) * scale;
+			for i in k..rows {
+				r[(i, j)] -= v[i] * s
+			}
+		}
+		for i in 0..rows {
+			let s = (k..rows).fold(zero, |a, j| a + q[(i, j)] * v[j]) * scale;
+			for j in k..rows {
+				q[(i, j)] -= s * v[j].conj()
+			}
+		}
+		for i in k + 1..rows {
+			r[(i, k)] = zero
+		}
+	}
+	for k in 0..rows.min(cols) {
+		if r[(k, k)] != zero {
+			let magnitude = (r[(k, k)].conj() * r[(k, k)]).sqrt();
+			let phase = r[(k, k)] / magnitude;
+			for j in 0..cols {
+				r[(k, j)] = phase.conj() * r[(k, j)]
+			}
+			r[(k, k)] = magnitude;
+			for i in 0..rows {
+				q[(i, k)] *= phase
+			}
+		}
+	}
+	(q, r)
+}
+
+impl<const T: usize, const N: usize, L: Field> QRDecompose<T, N, L> for Matrix<T, N, L> {
+	fn qr_decompose(&self) -> (Matrix<T, T, L>, Matrix<T, N, L>) {
+		householder_qr(Matrix::identity(), *self)
+	}
+	
+	fn thin_qr_decompose(&self) -> (Matrix<T, N, L>, Matrix<N, N, L>) {
+		const { assert!(T >= N, "A thin QR decomposition needs at least as many rows as columns") }
+		let (q, r) = self.qr_decompose();
+		let mut thin_q = Matrix::empty();
+		let mut thin_r = Matrix::empty();
+		for i in 0..T {
+			for j in 0..N {
+				thin_q[(i, j)] = q[(i, j)];
+				if i < N {
+					thin_r[(i, j)] = r[(i, j)]
+				}
+			}
+		}
+		(thin_q, thin_r)
+	}
+	
+	fn gram_schmidt_qr_decompose(&self) -> Result<(Matrix<T, N, L>, Matrix<N, N, L>), Error> {
+		assert_normed::<L>();
+		let zero = L::zero();
+		let tolerance = T as f64 * L::EPSILON * self.max_abs();
+		let mut q = *self;
+		let mut r = Matrix::<N, N, L>::empty();
+		for k in 0..N {
+			let norm = (0..T).fold(zero, |a, i| a + q[(i, k)].conj() * q[(i, k)]).sqrt();
+			if norm.modulus() <= tolerance {
+				return Err(Error::SingularMatrix)
+			}
+			r[(k, k)] = norm;
+			for i in 0..T {
+				q[(i, k)] /= norm
+			}
+			for j in k + 1..N {
+				r[(k, j)] = (0..T).fold(zero, |a, i| a + q[(i, k)].conj() * q[(i, j)]);
+				for i in 0..T {
+					let projection = q[(i, k)] * r[(k, j)];
+					q[(i, j)] -= projection
+				}
+			}
+		}
+		Ok((q, r))
+	}
+}
+
+impl<const T: usize, const N: usize, L: Field> BoxedMatrix<T, N, L> {
+	/// Generate the full QR decomposition of a matrix
+	///
+	/// This is the same as [`QRDecompose::qr_decompose`] but keeps `Q` and `R` on the heap
+	pub fn qr_decompose(&self) -> (BoxedMatrix<T, T, L>, Self) {
+		householder_qr(BoxedMatrix::identity(), self.clone())
+	}
+}
+
+impl<L: Field> DMatrix<L> {
+	/// Generate the full QR decomposition of a matrix
+	///
+	/// This is the same as [`QRDecompose::qr_decompose`] for a runtime sized matrix, returning an
+	/// orthogonal or unitary `Q` and an upper triangular `R` such that `QR=A`
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_rows(vec![vec![3f64, 0.], vec![4., 5.], vec![0., 4.]]);
+	/// let (q, r) = a.qr_decompose();
+	/// assert_eq!((q.shape(), r.shape()), ((3, 3), (3, 2)));
+	/// assert!((r[(0, 0)] - 5.).abs() < 1e-14 && (r[(1, 1)] - 5.).abs() < 1e-14)
+	/// ```
+	pub fn qr_decompose(&self) -> (Self, Self) {
+		householder_qr(Self::identity(self.rows), self.clone())
+	}
+}
+
+impl<L: ComplexField> DMatrix<L> {
+	/// Attempts to generate the singular value decomposition of a matrix
+	///
+	/// This is the same as [`SVDecompose::sv_decompose`] for a runtime sized matrix, returning
+	/// `(U, s, Vᵀ)` where `s` has one value for each column
+	pub fn sv_decompose(&self) -> Result<(Self, Vec<L>, Self), Error> {
+		let (u, values, v) = singular_value_decomposition(self, true)?;
+		let tolerance = self.rows.max(self.cols) as f64 * L::EPSILON;
+		let mut left = Self::empty(self.rows, self.rows);
+		for i in 0..self.rows {
+			for j in 0..self.rows {
+				left[(i, j)] = from_singular(u[i][j], tolerance)?
+			}
+		}
+		let mut right = Self::empty(self.cols, self.cols);
+		for i in 0..self.cols {
+			for j in 0..self.cols {
+				right[(i, j)] = from_singular(v[j][i].conj(), tolerance)?
+			}
+		}
+		Ok((left, self.singular_value_list(values)?, right))
+	}
+	
+	/// Attempts to calculate the singular values of a matrix in decreasing order
+	///
+	/// This gives the same values as [`sv_decompose`][DMatrix::sv_decompose] but is cheaper since
+	/// `U` and `V` are not calculated
+	pub fn singular_values(&self) -> Result<Vec<L>, Error> {
+		let (_, values, _) = singular_value_decomposition(self, false)?;
+		self.singular_value_list(values)
+	}
+	
+	/// Convert singular values into one value for each column, padding them with zeros if there
+	/// are fewer
+	fn singular_value_list(&self, values: Vec<f64>) -> Result<Vec<L>, Error> {
+		let mut out = vec![L::zero(); self.cols];
+		for (j, value) in values.into_iter().enumerate() {
+			out[j] = from_singular(Complex::from_real(value), 0.)?
+		}
+		Ok(out)
+	}
+}
+
+/// Check if a square matrix is symmetric, or Hermitian for complex dtypes, to within `tolerance`
+fn is_hermitian<L: Scalar, M: Dense<L>>(matrix: &M, tolerance: f64) -> bool {
+	let (rows, cols) = matrix.shape();
+	rows == cols && (0..rows).all(|i| (i..rows).all(|j| (matrix[(i, j)] - matrix[(j, i)].conj()).modulus() <= tolerance))
+}
+
+/// Cholesky decomposition trait
+///
+/// This is implemented for square matrices with a [`Field`] dtype. Integer dtypes aren't
+/// supported since the square roots and divisions would be truncated.
+pub trait CholeskyDecompose {
+	/// Attempts to generate the Cholesky decomposition of a symmetric positive-definite matrix
+	///
+	/// The Cholesky decomposition of a matrix `A` is a lower triangular matrix `L` with a positive
+	/// diagonal such that `LLᵀ=A`. For complex dtypes the matrix must be Hermitian positive-definite
+	/// and `LLᴴ=A` instead. This is about twice as fast as the [LU decomposition][LUDecompose].
+	///
+	/// This returns [`Error::NotSymmetric`] or [`Error::NotPositiveDefinite`] if the matrix
+	/// isn't symmetric positive-definite.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::CholeskyDecompose;
+	/// let a = Matrix::new([[4, 12, -16], [12, 37, -43], [-16, -43, 98]]).dtype::<f64>();
+	/// let l = a.cholesky_decompose().unwrap();
+	/// assert_eq!(l, Matrix::new([[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]]));
+	/// assert_eq!(l * l.transpose(), a)
+	/// ```
+	fn cholesky_decompose(&self) -> Result<Self, Error> where Self: Sized;
+}
+
+/// Cholesky decomposition of a matrix, treating values no larger than `tolerance` as zero
+fn cholesky<L: Field, M: Dense<L>>(matrix: &M, tolerance: f64) -> Result<M, Error> {
+	assert_normed::<L>();
+	let two = L::from_usize(2);
+	if !is_hermitian(matrix, tolerance) {
+		return Err(Error::NotSymmetric)
+	}
+	let size = matrix.shape().0;
+	let mut lower = matrix.zeros_like();
+	for j in 0..size {
+		let mut d = matrix[(j, j)];
+		for k in 0..j {
+			d -= lower[(j, k)] * lower[(j, k)].conj()
+		}
+		let d = (d + d.conj()) / two;
+		if d.to_complex().real <= tolerance {
+			return Err(Error::NotPositiveDefinite)
+		}
+		lower[(j, j)] = d.sqrt();
+		for i in j + 1..size {
+			let mut value = matrix[(i, j)];
+			for k in 0..j {
+				value -= lower[(i, k)] * lower[(j, k)].conj()
+			}
+			lower[(i, j)] = value / lower[(j, j)]
+		}
+	}
+	Ok(lower)
+}
+
+impl<const T: usize, L: Field> CholeskyDecompose for Matrix<T, T, L> {
+	fn cholesky_decompose(&self) -> Result<Self, Error> {
+		cholesky(self, T as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+impl<const T: usize, L: Field> CholeskyDecompose for BoxedMatrix<T, T, L> {
+	fn cholesky_decompose(&self) -> Result<Self, Error> {
+		cholesky(self, T as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+impl<L: Field> CholeskyDecompose for DMatrix<L> {
+	/// Returns [`Error::DimensionMismatch`] if the matrix is not square
+	fn cholesky_decompose(&self) -> Result<Self, Error> {
+		square(self)?;
+		cholesky(self, self.rows as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+/// LDLᵀ decomposition trait
+///
+/// This is implemented for square matrices with a [`Field`] dtype. Integer dtypes aren't
+/// supported since the divisions would be truncated.
+pub trait LDLDecompose {
+	/// Attempts to generate the LDLᵀ decomposition of a symmetric matrix
+	///
+	/// The LDLᵀ decomposition of a matrix `A` is a lower triangular matrix `L` with ones on the
+	/// diagonal and a diagonal matrix `D` such that `LDLᵀ=A`. For complex dtypes the matrix must be
+	/// Hermitian and `LDLᴴ=A` instead. This returns `(L, D)`.
+	///
+	/// Unlike the [Cholesky decomposition][CholeskyDecompose] this needs no square roots and works
+	/// for indefinite matrices, which have negative values in `D`. No pivoting is done so this
+	/// returns [`Error::ZeroPivot`] if a zero pivot is found, and [`Error::NotSymmetric`] if the
+	/// matrix isn't symmetric.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::LDLDecompose;
+	/// let a = Matrix::new([[1, 2], [2, 1]]).dtype::<f64>();
+	/// let (l, d) = a.ldl_decompose().unwrap();
+	/// assert_eq!(l, Matrix::new([[1., 0.], [2., 1.]]));
+	/// assert_eq!(d, Matrix::new([[1., 0.], [0., -3.]]));
+	/// assert_eq!(l * d * l.transpose(), a)
+	/// ```
+	fn ldl_decompose(&self) -> Result<(Self, Self), Error> where Self: Sized;
+}
+
+/// LDLᴴ decomposition of a matrix, treating values no larger than `tolerance` as zero
+fn ldl<L: Field, M: Dense<L>>(matrix: &M, tolerance: f64) -> Result<(M, M), Error> {
+	if !is_hermitian(matrix, tolerance) {
+		return Err(Error::NotSymmetric)
+	}
+	let size = matrix.shape().0;
+	let mut lower = matrix.identity_like();
+	let mut diagonal = matrix.zeros_like();
+	for j in 0..size {
+		let mut d = matrix[(j, j)];
+		for k in 0..j {
+			d -= lower[(j, k)] * lower[(j, k)].conj() * diagonal[(k, k)]
+		}
+		if d.modulus() <= tolerance {
+			return Err(Error::ZeroPivot { row: j, col: j })
+		}
+		diagonal[(j, j)] = d;
+		for i in j + 1..size {
+			let mut value = matrix[(i, j)];
+			for k in 0..j {
+				value -= lower[(i, k)] * lower[(j, k)].conj() * diagonal[(k, k)]
+			}
+			lower[(i, j)] = value / d
+		}
+	}
+	Ok((lower, diagonal))
+}
+
+impl<const T: usize, L: Field> LDLDecompose for Matrix<T, T, L> {
+	fn ldl_decompose(&self) -> Result<(Self, Self), Error> {
+		ldl(self, T as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+impl<const T: usize, L: Field> LDLDecompose for BoxedMatrix<T, T, L> {
+	fn ldl_decompose(&self) -> Result<(Self, Self), Error> {
+		ldl(self, T as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+impl<L: Field> LDLDecompose for DMatrix<L> {
+	/// Returns [`Error::DimensionMismatch`] if the matrix is not square
+	fn ldl_decompose(&self) -> Result<(Self, Self), Error> {
+		square(self)?;
+		ldl(self, self.rows as f64 * L::EPSILON * self.max_abs())
+	}
+}
+
+/// Singular value decomposition trait
+///
+/// This is implemented for rectangular matrices of any shape with a [`ComplexField`] dtype, which
+/// is `f32`, `f64`, and complex values of them. The [2-norm][Matrix::norm_2] and
+/// [condition number][Matrix::condition_number] use the singular values of any dtype.
+pub trait SVDecompose<const T: usize, const N: usize, L: Copy + Debug> {
+	/// Attempts to generate the singular value decomposition of a matrix
+	///
+	/// The singular value decomposition of a `T`×`N` matrix `A` is a `T`×`T` orthogonal matrix `U`,
+	/// a `T`×`N` diagonal matrix `Σ` with non-negative values, and an `N`×`N` orthogonal matrix `V`
+	/// such that `UΣVᵀ=A`. For complex dtypes `U` and `V` are unitary and `UΣVᴴ=A` instead. This
+	/// returns `(U, s, Vᵀ)` where `s` is the diagonal of `Σ` in decreasing order. If `T<N` then
+	/// only the first `T` values of `s` are singular values and the rest are zero.
+	///
+	/// This uses Golub–Kahan bidiagonalisation followed by implicitly shifted QR sweeps. It returns
+	/// [`Error::NoConvergence`] if these do not converge, and [`Error::ConversionOverflow`] if the
+	/// result can't be represented in the dtype.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::SVDecompose;
+	/// let a = Matrix::new([[3, 0], [4, 5]]).dtype::<f64>();
+	/// let (u, s, vt) = a.sv_decompose().unwrap();
+	/// assert!((s[(0, 0)] - 45f64.sqrt()).abs() < 1e-14);
+	/// assert!((s[(0, 1)] - 5f64.sqrt()).abs() < 1e-14);
+	/// let check = u * Matrix::new([[s[(0, 0)], 0.], [0., s[(0, 1)]]]) * vt - a;
+	/// assert!((0..2).all(|i| (0..2).all(|j| check[(i, j)].abs() < 1e-14)))
+	/// ```
+	#[allow(clippy::type_complexity)]
+	fn sv_decompose(&self) -> Result<(Matrix<T, T, L>, Vector<N, L>, Matrix<N, N, L>), Error>;
+	
+	/// Attempts to calculate the singular values of a matrix
+	///
+	/// This gives the same values as [`sv_decompose`][SVDecompose::sv_decompose] but is cheaper
+	/// since `U` and `V` are not calculated.
+	fn singular_values(&self) -> Result<Vector<N, L>, Error>;
+}
+
+/// Convert a singular value or vector back into the dtype, returning
+/// [`Error::ConversionOverflow`] if it can't be represented to within `tolerance`
+fn from_singular<L: Scalar>(value: Complex<f64>, tolerance: f64) -> Result<L, Error> {
+	L::from_complex(value, tolerance).ok_or(Error::ConversionOverflow)
+}
+
+/// Convert singular values into a vector of length `N`, padding them with zeros if there are fewer
+fn singular_value_vector<const N: usize, L: Scalar>(values: Vec<f64>) -> Result<Vector<N, L>, Error> {
+	let mut out = Vector::<N, L>::empty();
+	for (j, value) in values.into_iter().enumerate() {
+		out[(0, j)] = from_singular(Complex::from_real(value), 0.)?
+	}
+	Ok(out)
+}
+
+/// Calculate the singular value decomposition `(U, s, V)` of a matrix with [`eigen::svd`], which
+/// needs at least as many rows as columns, by decomposing `Aᴴ` instead if there are fewer rows.
+/// Returns [`Error::NoConvergence`] if the iteration does not converge
+pub(crate) fn singular_value_decomposition<L: Scalar, M: Dense<L>>(matrix: &M, vectors: bool) -> Result<(eigen::Rows, Vec<f64>, eigen::Rows), Error> {
+	assert_normed::<L>();
+	let (rows, cols) = matrix.shape();
+	if rows >= cols {
+		let a = (0..rows).map(|i| (0..cols).map(|j| matrix[(i, j)].to_complex()).collect()).collect();
+		eigen::svd(a, vectors).ok_or(Error::NoConvergence)
+	} else {
+		let a = (0..cols).map(|j| (0..rows).map(|i| matrix[(i, j)].conj().to_complex()).collect()).collect();
+		let (v, values, u) = eigen::svd(a, vectors).ok_or(Error::NoConvergence)?;
+		Ok((u, values, v))
+	}
+}
+
+impl<const T: usize, const N: usize, L: ComplexField> SVDecompose<T, N, L> for Matrix<T, N, L> {
+	#[allow(clippy::type_complexity)]
+	fn sv_decompose(&self) -> Result<(Matrix<T, T, L>, Vector<N, L>, Matrix<N, N, L>), Error> {
+		let (u, values, v) = singular_value_decomposition(self, true)?;
+		let tolerance = T.max(N) as f64 * L::EPSILON;
+		let mut left = Matrix::<T, T, L>::empty();
+		for i in 0..T {
+			for j in 0..T {
+				left[(i, j)] = from_singular(u[i][j], tolerance)?
+			}
+		}
+		let mut right = Matrix::<N, N, L>::empty();
+		for i in 0..N {
+			for j in 0..N {
+				right[(i, j)] = from_singular(v[j][i].conj(), tolerance)?
+			}
+		}
+		Ok((left, singular_value_vector(values)?, right))
+	}
+	
+	fn singular_values(&self) -> Result<Vector<N, L>, Error> {
+		let (_, values, _) = singular_value_decomposition(self, false)?;
+		singular_value_vector(values)
+	}
+}
+
+/// Symmetric eigenvalue trait
+///
+/// This is implemented for square matrices of [real fields][RealField] such as `f32` and `f64`
+pub trait SymmetricEigen<const T: usize, L: Copy + Debug> {
+	/// Attempts to calculate the eigenvalues and eigenvectors of a symmetric matrix
+	///
+	/// The eigenvalues of a symmetric matrix are always real and it has an orthogonal matrix of
+	/// eigenvectors. This returns `(λ, Q)` where `λ` are the eigenvalues in ascending order and the
+	/// columns of `Q` are the matching eigenvectors, so `Q diag(λ) Qᵀ=A`. Each eigenvector is
+	/// scaled so its largest value is positive.
+	///
+	/// This uses cyclic Jacobi rotations which are more accurate than the general
+	/// [`eigenvalues`][Matrix::eigenvalues]. It returns [`Error::NotSymmetric`] if the matrix is not
+	/// symmetric and [`Error::NoConvergence`] if the rotations do not converge.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::decompose::SymmetricEigen;
+	/// let a = Matrix::new([[2, 1], [1, 2]]).dtype::<f64>();
+	/// let (values, q) = a.symmetric_eigen().unwrap();
+	/// assert!((values[(0, 0)] - 1.).abs() < 1e-15 && (values[(0, 1)] - 3.).abs() < 1e-15);
+	/// # let check = q * Matrix::new([[values[(0, 0)], 0.], [0., values[(0, 1)]]]) * q.transpose() - a;
+	/// # assert!((0..2).all(|i| (0..2).all(|j| check[(i, j)].abs() < 1e-14)))
+	/// ```
+	fn symmetric_eigen(&self) -> Result<(Vector<T, L>, Matrix<T, T, L>), Error>;
+}
+
+impl<const T: usize, L: RealField> SymmetricEigen<T, L> for Matrix<T, T, L> {
+	fn symmetric_eigen(&self) -> Result<(Vector<T, L>, Matrix<T, T, L>), Error> {
+		let tolerance = T as f64 * L::EPSILON * self.max_abs();
+		if !is_hermitian(self, tolerance) {
+			return Err(Error::NotSymmetric)
+		}
+		let (values, vectors) = eigen::jacobi(self.0.map(|row| row.map(|v| v.to_f64()))).ok_or(Error::NoConvergence)?;
+		Ok((Vector::new([values.map(L::from_f64)]), Matrix::new(vectors.map(|row| row.map(L::from_f64)))))
+	}
 }
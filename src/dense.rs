@@ -0,0 +1,81 @@
+//! Storage shared by fixed size and runtime sized matrices
+//!
+//! The elimination based algorithms only need to read and write values and swap rows, so they are
+//! written once against the [`Dense`] trait and used by [`Matrix`], [`BoxedMatrix`] and [`DMatrix`]
+
+use std::fmt::Debug;
+use std::ops::IndexMut;
+use crate::prelude::{BoxedMatrix, DMatrix, Matrix};
+use crate::scalar::{One, Zero};
+
+/// A dense matrix indexed by `(row, column)`
+pub(crate) trait Dense<L: Copy + Debug>: Clone + IndexMut<(usize, usize), Output=L> {
+	/// Return the number of rows and columns
+	fn shape(&self) -> (usize, usize);
+	
+	/// Swap rows `i` and `j` in place
+	fn swap_rows(&mut self, i: usize, j: usize);
+	
+	/// Return a matrix of zeros with the same shape
+	fn zeros_like(&self) -> Self where L: Zero;
+	
+	/// Return an identity matrix with the same shape, which must be square
+	fn identity_like(&self) -> Self where L: Zero + One {
+		let mut out = self.zeros_like();
+		for i in 0..self.shape().0 {
+			out[(i, i)] = L::one()
+		}
+		out
+	}
+	
+	/// Swap columns `i` and `j` in place
+	fn swap_cols(&mut self, i: usize, j: usize) {
+		for r in 0..self.shape().0 {
+			let temp = self[(r, i)];
+			self[(r, i)] = self[(r, j)];
+			self[(r, j)] = temp
+		}
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Dense<L> for Matrix<T, N, L> {
+	fn shape(&self) -> (usize, usize) {
+		(T, N)
+	}
+	
+	fn swap_rows(&mut self, i: usize, j: usize) {
+		self.0.swap(i, j)
+	}
+	
+	fn zeros_like(&self) -> Self where L: Zero {
+		Self::empty()
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Dense<L> for BoxedMatrix<T, N, L> {
+	fn shape(&self) -> (usize, usize) {
+		(T, N)
+	}
+	
+	fn swap_rows(&mut self, i: usize, j: usize) {
+		self.0.swap(i, j)
+	}
+	
+	fn zeros_like(&self) -> Self where L: Zero {
+		Self::empty()
+	}
+}
+
+impl<L: Copy + Debug> Dense<L> for DMatrix<L> {
+	fn shape(&self) -> (usize, usize) {
+		(self.rows, self.cols)
+	}
+	
+	fn swap_rows(&mut self, i: usize, j: usize) {
+		DMatrix::swap_rows(self, i, j)
+	}
+	
+	fn zeros_like(&self) -> Self where L: Zero {
+		Self::empty(self.rows, self.cols)
+	}
+}
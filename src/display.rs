@@ -1,34 +1,74 @@
 use std::fmt::{Formatter, Display, Debug};
-use crate::{Matrix, Vector, polynomials::Polynomial};
-use conv::{ConvUtil, ValueFrom, ValueInto};
+use crate::{Matrix, polynomials::Polynomial};
+use std::ops::Neg;
+use crate::scalar::{One, Zero};
 use itertools::Itertools;
 
 impl<const T: usize, const N: usize, L: Copy + Debug + Display> Display for Matrix<T, N, L> {
+	/// Format a matrix
+	///
+	/// By default a matrix is written on one line as nested brackets, `[[1, 2], [3, 4]]`. Using the
+	/// alternate flag (`{:#}`) writes each row on its own line between Unicode matrix brackets with
+	/// every column right-aligned to its widest value
+	/// ```text
+	/// ⎡ 1  -2 ⎤
+	/// ⎣ 3  40 ⎦
+	/// ```
+	///
+	/// The precision (`{:.3}`) is passed to each value and the width (`{:8}`) is the minimum width
+	/// of each value in either form.
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		todo!()
+		let values = self.0.iter().map(|row| row.iter().map(|v| match f.precision() {
+			Some(e) => format!("{:.e$}", v),
+			None => format!("{}", v)
+		}).collect::<Vec<String>>()).collect::<Vec<Vec<String>>>();
+		let mut widths = [f.width().unwrap_or(0); N];
+		if f.alternate() {
+			for row in values.iter() {
+				for (width, value) in widths.iter_mut().zip(row.iter()) {
+					*width = (*width).max(value.chars().count())
+				}
+			}
+		}
+		let rows = values.iter().map(|row| {
+			row.iter().zip(widths.iter()).map(|(value, width)| format!("{:>width$}", value)).collect::<Vec<String>>()
+		}).collect::<Vec<Vec<String>>>();
+		if !f.alternate() {
+			return write!(f, "[{}]", rows.iter().map(|row| format!("[{}]", row.join(", "))).join(", "))
+		}
+		match T {
+			0 => write!(f, "[]"),
+			1 => write!(f, "[ {} ]", rows[0].join("  ")),
+			_ => write!(f, "{}", rows.iter().enumerate().map(|(n, row)| {
+				let (open, close) = match n {
+					0 => ('⎡', '⎤'),
+					n if n == T - 1 => ('⎣', '⎦'),
+					_ => ('⎢', '⎥')
+				};
+				format!("{} {} {}", open, row.join("  "), close)
+			}).join("\n"))
+		}
 	}
 }
 
-impl<L: Copy + Debug + Display> Display for Polynomial<L> where L: ValueFrom<isize> + PartialEq {
+impl<L: Copy + Debug + Display> Display for Polynomial<L> where L: Zero + One + Neg<Output=L> + PartialEq {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(
 			f, "{}",
 			self.0.iter().enumerate().filter_map(|(v, c)| {
-				if c == &0.value_as::<L>().unwrap() {
+				if c == &L::zero() {
 					None
 				} else {
 					Some(format!(
 						"{}{}",
-						if c == &1.value_as::<L>().unwrap() { "+".to_string() } else if c == &(-1).value_as::<L>().unwrap() { "-".to_string() } else {
+						if c == &L::one() { "+".to_string() } else if c == &-L::one() { "-".to_string() } else {
 							let e = f.precision().unwrap_or(0);
 							format!("{:+.e$?}", c)
 						},
-						if v == self.0.len() - 1 { String::new() } else {
-							if self.degree() - v == 1 { "x".to_string() } else {
-								format!("x{}", format!("{}", self.degree() - v).chars().map(|t| {
-									"⁰¹²³⁴⁵⁶⁷⁸⁹".chars().nth((t as u8) as usize - 48).unwrap().to_string()
-								}).join(""))
-							}
+						if v == self.0.len() - 1 { String::new() } else if self.degree() - v == 1 { "x".to_string() } else {
+							format!("x{}", format!("{}", self.degree() - v).chars().map(|t| {
+								"⁰¹²³⁴⁵⁶⁷⁸⁹".chars().nth((t as u8) as usize - 48).unwrap().to_string()
+							}).join(""))
 						}
 					))
 				}
@@ -0,0 +1,321 @@
+//! Implementations for the runtime sized [`DMatrix`]
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use conv::{ConvUtil, ValueFrom};
+use crate::prelude::{DMatrix, Matrix};
+use crate::scalar::{One, Scalar, Zero};
+use crate::Error;
+use crate::ops::determinant;
+
+impl<L: Copy + Debug> DMatrix<L> {
+	/// Generate a new `rows`×`cols` matrix from a vector of values in row-major order
+	///
+	/// This will panic if there aren't exactly `rows * cols` values
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+	/// assert_eq!(a[(1, 0)], 4)
+	/// ```
+	pub fn new(rows: usize, cols: usize, data: Vec<L>) -> Self {
+		assert_eq!(data.len(), rows * cols, "A {}×{} matrix needs {} values", rows, cols, rows * cols);
+		Self { rows, cols, data }
+	}
+	
+	/// Generate a new matrix from a vector of rows
+	///
+	/// This will panic if the rows are different lengths
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+	/// assert_eq!(a, DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]))
+	/// ```
+	pub fn from_rows(rows: Vec<Vec<L>>) -> Self {
+		let cols = rows.first().map_or(0, |row| row.len());
+		assert!(rows.iter().all(|row| row.len() == cols), "Each row of a matrix must be the same length");
+		Self { rows: rows.len(), cols, data: rows.into_iter().flatten().collect() }
+	}
+	
+	/// Generate a new `rows`×`cols` matrix with `f(i, j)` in row `i` and column `j`
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_fn(2, 2, |i, j| i * 2 + j);
+	/// assert_eq!(a, DMatrix::from_rows(vec![vec![0, 1], vec![2, 3]]))
+	/// ```
+	pub fn from_fn<F: FnMut(usize, usize) -> L>(rows: usize, cols: usize, mut f: F) -> Self {
+		let mut data = Vec::with_capacity(rows * cols);
+		for i in 0..rows {
+			for j in 0..cols {
+				data.push(f(i, j))
+			}
+		}
+		Self { rows, cols, data }
+	}
+	
+	/// Generate an empty `rows`×`cols` matrix
+	/// ```
+	/// # use lineas::DMatrix;
+	/// assert_eq!(DMatrix::<i32>::empty(1, 2), DMatrix::from_rows(vec![vec![0, 0]]))
+	/// ```
+	pub fn empty(rows: usize, cols: usize) -> Self where L: Zero {
+		Self { rows, cols, data: vec![L::zero(); rows * cols] }
+	}
+	
+	/// Generate a `size`×`size` identity matrix
+	/// ```
+	/// # use lineas::DMatrix;
+	/// assert_eq!(DMatrix::<i32>::identity(2), DMatrix::from_rows(vec![vec![1, 0], vec![0, 1]]))
+	/// ```
+	pub fn identity(size: usize) -> Self where L: Zero + One {
+		let mut out = Self::empty(size, size);
+		for i in 0..size {
+			out[(i, i)] = L::one()
+		}
+		out
+	}
+	
+	/// Return the number of rows and columns as `(rows, cols)`
+	pub fn shape(&self) -> (usize, usize) {
+		(self.rows, self.cols)
+	}
+	
+	/// Return the values of the matrix in row-major order
+	pub fn as_slice(&self) -> &[L] {
+		&self.data
+	}
+	
+	/// Transform the type of the data to the specified type
+	///
+	/// This has the same requirements as [`Matrix::dtype`]
+	pub fn dtype<Q>(self) -> DMatrix<Q> where Q: Copy + Debug + ValueFrom<L> {
+		self.try_dtype().expect("Values could not be converted to the new dtype")
+	}
+	
+	/// Transform the type of the data to the specified type
+	///
+	/// Returns [`Error::ConversionOverflow`] if any value can't be represented in the new dtype
+	/// ```
+	/// # use lineas::{DMatrix, Error};
+	/// assert_eq!(DMatrix::from_rows(vec![vec![-1]]).try_dtype::<u32>(), Err(Error::ConversionOverflow))
+	/// ```
+	pub fn try_dtype<Q>(self) -> Result<DMatrix<Q>, Error> where Q: Copy + Debug + ValueFrom<L> {
+		let data = self.data.into_iter().map(|v| v.value_as::<Q>()).collect::<Result<Vec<Q>, _>>().map_err(|_| Error::ConversionOverflow)?;
+		Ok(DMatrix { rows: self.rows, cols: self.cols, data })
+	}
+	
+	/// Transpose the matrix
+	///
+	/// Returns the transposed matrix, leaving the original unchanged
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_rows(vec![vec![1, 2, 3]]);
+	/// assert_eq!(a.transpose(), DMatrix::from_rows(vec![vec![1], vec![2], vec![3]]))
+	/// ```
+	pub fn transpose(&self) -> Self {
+		Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+	}
+	
+	/// Scale the matrix
+	///
+	/// Returns the scaled matrix and does not alter the original
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]);
+	/// assert_eq!(a.scale(2), DMatrix::from_rows(vec![vec![2, 4], vec![6, 8]]))
+	/// ```
+	pub fn scale<V>(&self, v: V) -> Self where L: Mul<Output=L> + ValueFrom<V> {
+		let mut out = self.clone();
+		out.scale_set(v);
+		out
+	}
+	
+	/// Scale the matrix in place
+	pub fn scale_set<V>(&mut self, v: V) where L: Mul<Output=L> + ValueFrom<V> {
+		let multiplier: L = v.value_as().unwrap();
+		for value in self.data.iter_mut() {
+			*value = *value * multiplier
+		}
+	}
+	
+	/// Swap rows `i` and `j` of the matrix in place
+	///
+	/// This will panic if either row is out of bounds
+	pub fn swap_rows(&mut self, i: usize, j: usize) {
+		assert!(i < self.rows && j < self.rows, "Cannot swap rows {} and {} of a matrix with {} rows", i, j, self.rows);
+		for k in 0..self.cols {
+			self.data.swap(i * self.cols + k, j * self.cols + k)
+		}
+	}
+	
+	/// Return the largest absolute value in the matrix
+	pub fn max_abs(&self) -> f64 where L: Scalar {
+		self.data.iter().map(|v| v.modulus()).fold(0., f64::max)
+	}
+	
+	/// Returns the determinant of a square matrix
+	///
+	/// This uses the same algorithms as [`Matrix::determinant`] and will panic if the matrix is not
+	/// square
+	/// ```
+	/// # use lineas::DMatrix;
+	/// let a = DMatrix::from_rows(vec![vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+	/// assert_eq!(a.determinant(), 49)
+	/// ```
+	pub fn determinant(&self) -> L where L: Scalar {
+		assert_eq!(self.rows, self.cols, "The determinant is only defined for square matrices");
+		determinant(self)
+	}
+}
+
+impl<L: Copy + Debug> Index<(usize, usize)> for DMatrix<L> {
+	type Output = L;
+	
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		assert!(index.0 < self.rows && index.1 < self.cols, "Index {:?} is out of bounds for a {}×{} matrix", index, self.rows, self.cols);
+		&self.data[index.0 * self.cols + index.1]
+	}
+}
+
+impl<L: Copy + Debug> IndexMut<(usize, usize)> for DMatrix<L> {
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		assert!(index.0 < self.rows && index.1 < self.cols, "Index {:?} is out of bounds for a {}×{} matrix", index, self.rows, self.cols);
+		&mut self.data[index.0 * self.cols + index.1]
+	}
+}
+
+impl<L: Copy + Debug> AddAssign<&DMatrix<L>> for DMatrix<L> where L: AddAssign {
+	fn add_assign(&mut self, rhs: &DMatrix<L>) {
+		assert_eq!(self.shape(), rhs.shape(), "Cannot add matrices of different sizes");
+		for (value, other) in self.data.iter_mut().zip(rhs.data.iter()) {
+			*value += *other
+		}
+	}
+}
+
+impl<L: Copy + Debug> AddAssign for DMatrix<L> where L: AddAssign {
+	fn add_assign(&mut self, rhs: Self) {
+		*self += &rhs
+	}
+}
+
+impl<L: Copy + Debug> Add for &DMatrix<L> where L: AddAssign {
+	type Output = DMatrix<L>;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out += rhs;
+		out
+	}
+}
+
+impl<L: Copy + Debug> Add for DMatrix<L> where L: AddAssign {
+	type Output = Self;
+	
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += &rhs;
+		self
+	}
+}
+
+impl<L: Copy + Debug> SubAssign<&DMatrix<L>> for DMatrix<L> where L: SubAssign {
+	fn sub_assign(&mut self, rhs: &DMatrix<L>) {
+		assert_eq!(self.shape(), rhs.shape(), "Cannot subtract matrices of different sizes");
+		for (value, other) in self.data.iter_mut().zip(rhs.data.iter()) {
+			*value -= *other
+		}
+	}
+}
+
+impl<L: Copy + Debug> SubAssign for DMatrix<L> where L: SubAssign {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self -= &rhs
+	}
+}
+
+impl<L: Copy + Debug> Sub for &DMatrix<L> where L: SubAssign {
+	type Output = DMatrix<L>;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		let mut out = self.clone();
+		out -= rhs;
+		out
+	}
+}
+
+impl<L: Copy + Debug> Sub for DMatrix<L> where L: SubAssign {
+	type Output = Self;
+	
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= &rhs;
+		self
+	}
+}
+
+impl<L: Copy + Debug> Mul for &DMatrix<L> where L: AddAssign + Mul<Output=L> + Zero {
+	type Output = DMatrix<L>;
+	
+	/// Multiply two matrices together
+	///
+	/// The first matrix must have as many columns as the second matrix has rows, otherwise this
+	/// will panic
+	fn mul(self, rhs: Self) -> Self::Output {
+		assert_eq!(self.cols, rhs.rows, "Cannot multiply a {}×{} matrix by a {}×{} matrix", self.rows, self.cols, rhs.rows, rhs.cols);
+		let mut out = DMatrix::empty(self.rows, rhs.cols);
+		for i in 0..self.rows {
+			for k in 0..self.cols {
+				let value = self[(i, k)];
+				for j in 0..rhs.cols {
+					out[(i, j)] += value * rhs[(k, j)]
+				}
+			}
+		}
+		out
+	}
+}
+
+impl<L: Copy + Debug> Mul for DMatrix<L> where L: AddAssign + Mul<Output=L> + Zero {
+	type Output = Self;
+	
+	fn mul(self, rhs: Self) -> Self::Output {
+		&self * &rhs
+	}
+}
+
+impl<L: Copy + Debug> Neg for &DMatrix<L> where L: Neg<Output=L> {
+	type Output = DMatrix<L>;
+	
+	fn neg(self) -> Self::Output {
+		-self.clone()
+	}
+}
+
+impl<L: Copy + Debug> Neg for DMatrix<L> where L: Neg<Output=L> {
+	type Output = Self;
+	
+	fn neg(mut self) -> Self::Output {
+		for value in self.data.iter_mut() {
+			*value = -*value
+		}
+		self
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> From<Matrix<T, N, L>> for DMatrix<L> {
+	fn from(value: Matrix<T, N, L>) -> Self {
+		Self { rows: T, cols: N, data: value.0.iter().flatten().copied().collect() }
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> TryFrom<DMatrix<L>> for Matrix<T, N, L> {
+	type Error = Error;
+	
+	/// Convert a runtime sized matrix into a fixed size matrix
+	///
+	/// Returns [`Error::DimensionMismatch`] if the matrix isn't `T`×`N`
+	fn try_from(value: DMatrix<L>) -> Result<Self, Self::Error> {
+		if value.shape() != (T, N) {
+			return Err(Error::DimensionMismatch { expected: (T, N), found: value.shape() })
+		}
+		Ok(Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| value.data[i * N + j]))))
+	}
+}
@@ -0,0 +1,645 @@
+//! Eigenvalue and singular value calculations
+//!
+//! These are the iterative algorithms used by the decompositions. They all work on arrays of
+//! `Complex<f64>` values, so matrices are converted with
+//! [`Scalar::to_complex`][crate::scalar::Scalar::to_complex] first and converted back afterwards.
+
+use crate::Complex;
+
+/// Maximum number of QR iterations per eigenvalue for each row of the matrix before giving up
+const MAX_ITERATIONS: usize = 30;
+
+/// Maximum number of Jacobi sweeps before giving up
+const MAX_SWEEPS: usize = 30;
+
+fn zero() -> Complex<f64> {
+	Complex::from_real(0.)
+}
+
+/// Reduce a matrix to upper Hessenberg form in place using Householder reflections
+///
+/// The result is unitarily similar to the original matrix so has the same eigenvalues
+pub(crate) fn hessenberg<const T: usize>(a: &mut [[Complex<f64>; T]; T]) {
+	for k in 0..T.saturating_sub(2) {
+		let norm = (k + 1..T).map(|i| a[i][k].abs().powi(2)).sum::<f64>().sqrt();
+		if norm == 0. {
+			continue
+		}
+		let phase = if a[k + 1][k].abs() == 0. {
+			Complex::from_real(1.)
+		} else {
+			a[k + 1][k] / Complex::from_real(a[k + 1][k].abs())
+		};
+		let mut v = [zero(); T];
+		for i in k + 1..T {
+			v[i] = a[i][k]
+		}
+		v[k + 1] += phase * Complex::from_real(norm);
+		let v_norm = Complex::from_real((k + 1..T).map(|i| v[i].abs().powi(2)).sum::<f64>().sqrt());
+		for value in v.iter_mut().skip(k + 1) {
+			*value /= v_norm
+		}
+		let mut s = [zero(); T];
+		for (row, vi) in a.iter().zip(v.iter()).skip(k + 1) {
+			for (sj, value) in s.iter_mut().zip(row.iter()) {
+				*sj += vi.conj() * *value
+			}
+		}
+		for (row, vi) in a.iter_mut().zip(v.iter()).skip(k + 1) {
+			for (value, sj) in row.iter_mut().zip(s.iter()) {
+				*value -= Complex::from_real(2.) * *vi * *sj
+			}
+		}
+		for row in a.iter_mut() {
+			let s = row.iter().zip(v.iter()).skip(k + 1).fold(zero(), |s, (x, y)| s + *x * *y);
+			for (value, vj) in row.iter_mut().zip(v.iter()).skip(k + 1) {
+				*value -= Complex::from_real(2.) * s * vj.conj()
+			}
+		}
+	}
+}
+
+/// Return `(c, s)` for the Givens rotation `[[c, s], [-s̄, c]]` that zeroes `b` in `[a, b]`
+fn givens(a: Complex<f64>, b: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+	let (abs_a, abs_b) = (a.abs(), b.abs());
+	if abs_b == 0. {
+		(Complex::from_real(1.), zero())
+	} else if abs_a == 0. {
+		(zero(), b.conj() / Complex::from_real(abs_b))
+	} else {
+		let norm = abs_a.hypot(abs_b);
+		(Complex::from_real(abs_a / norm), a / Complex::from_real(abs_a) * b.conj() / Complex::from_real(norm))
+	}
+}
+
+/// Eigenvalue of the 2×2 matrix `[[a, b], [c, d]]` closest to `d`
+fn wilkinson_shift(a: Complex<f64>, b: Complex<f64>, c: Complex<f64>, d: Complex<f64>) -> Complex<f64> {
+	let half = Complex::from_real(0.5);
+	let mean = (a + d) * half;
+	let difference = (a - d) * half;
+	let root = (difference * difference + b * c).sqrt();
+	let (first, second) = (mean + root, mean - root);
+	if (first - d).abs() < (second - d).abs() { first } else { second }
+}
+
+/// Do one shifted QR step on the rows and columns `lo..=hi` of an upper Hessenberg matrix
+fn qr_step<const T: usize>(a: &mut [[Complex<f64>; T]; T], lo: usize, hi: usize, shift: Complex<f64>) {
+	for (k, row) in a.iter_mut().enumerate().take(hi + 1).skip(lo) {
+		row[k] -= shift
+	}
+	let mut rotations = Vec::with_capacity(hi - lo);
+	for k in lo..hi {
+		let (c, s) = givens(a[k][k], a[k + 1][k]);
+		let (top, bottom) = a.split_at_mut(k + 1);
+		for (x, y) in top[k].iter_mut().zip(bottom[0].iter_mut()).take(hi + 1).skip(k) {
+			(*x, *y) = (c * *x + s * *y, c * *y - s.conj() * *x);
+		}
+		rotations.push((c, s));
+	}
+	for (k, (c, s)) in (lo..hi).zip(rotations) {
+		for row in a.iter_mut().take(k + 2).skip(lo) {
+			let (x, y) = (row[k], row[k + 1]);
+			row[k] = x * c + y * s.conj();
+			row[k + 1] = y * c - x * s;
+		}
+	}
+	for (k, row) in a.iter_mut().enumerate().take(hi + 1).skip(lo) {
+		row[k] += shift
+	}
+}
+
+/// Calculate the eigenvalues of a matrix
+///
+/// The matrix is reduced to upper Hessenberg form and then the shifted QR algorithm is used with
+/// Wilkinson shifts. Returns `None` if this does not converge.
+pub(crate) fn eigenvalues<const T: usize>(mut a: [[Complex<f64>; T]; T]) -> Option<[Complex<f64>; T]> {
+	let mut out = [zero(); T];
+	if T == 0 {
+		return Some(out)
+	}
+	hessenberg(&mut a);
+	let norm = a.iter().flatten().map(|z| z.abs()).fold(0., f64::max);
+	let mut hi = T - 1;
+	let mut iterations = 0;
+	while hi > 0 {
+		let mut lo = hi;
+		while lo > 0 {
+			let diagonal = a[lo][lo].abs() + a[lo - 1][lo - 1].abs();
+			if a[lo][lo - 1].abs() <= f64::EPSILON * if diagonal == 0. { norm } else { diagonal } {
+				a[lo][lo - 1] = zero();
+				break
+			}
+			lo -= 1;
+		}
+		if lo == hi {
+			out[hi] = a[hi][hi];
+			hi -= 1;
+			iterations = 0;
+			continue
+		}
+		iterations += 1;
+		if iterations > MAX_ITERATIONS * T {
+			return None
+		}
+		let shift = if iterations % 10 == 0 {
+			a[hi][hi] + Complex::from_real(0.75 * a[hi][hi - 1].abs())
+		} else {
+			wilkinson_shift(a[hi - 1][hi - 1], a[hi - 1][hi], a[hi][hi - 1], a[hi][hi])
+		};
+		qr_step(&mut a, lo, hi, shift);
+	}
+	out[0] = a[0][0];
+	Some(out)
+}
+
+/// Eigenvalues of the real 2×2 matrix `[[a, b], [c, d]]`
+fn real_pair(a: f64, b: f64, c: f64, d: f64) -> [Complex<f64>; 2] {
+	let p = (a - d) / 2.;
+	let q = p * p + b * c;
+	if q >= 0. {
+		let z = p + q.sqrt().copysign(p);
+		let other = if z == 0. { d } else { d - b * c / z };
+		[Complex::from_real(d + z), Complex::from_real(other)]
+	} else {
+		let imaginary = (-q).sqrt();
+		[Complex::from_complex(d + p, -imaginary), Complex::from_complex(d + p, imaginary)]
+	}
+}
+
+/// Apply the reflection `I - 2uuᵀ/uᵀu` on the indices from `start` to `a` from both sides
+///
+/// Only the columns in `columns` are updated from the left and only rows `lo..=bottom` are updated
+/// from the right since the rest are outside the active block or are zero
+fn reflect<const T: usize>(a: &mut [[f64; T]; T], u: &[f64], start: usize, columns: std::ops::RangeInclusive<usize>, lo: usize, bottom: usize) {
+	let scale = 2. / u.iter().map(|v| v * v).sum::<f64>();
+	for j in columns {
+		let s = u.iter().enumerate().map(|(i, v)| v * a[start + i][j]).sum::<f64>() * scale;
+		for (i, v) in u.iter().enumerate() {
+			a[start + i][j] -= s * v
+		}
+	}
+	for row in a.iter_mut().take(bottom + 1).skip(lo) {
+		let s = u.iter().enumerate().map(|(j, v)| v * row[start + j]).sum::<f64>() * scale;
+		for (j, v) in u.iter().enumerate() {
+			row[start + j] -= s * v
+		}
+	}
+}
+
+/// Householder vector `u` reflecting `x` onto a multiple of the first axis, or `None` if `x` is zero
+fn real_householder(x: &[f64]) -> Option<Vec<f64>> {
+	let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+	if norm == 0. {
+		return None
+	}
+	let mut u = x.to_vec();
+	u[0] += norm.copysign(x[0]);
+	Some(u)
+}
+
+/// Do one Francis double-shift QR step on the rows and columns `lo..=hi` of a real upper
+/// Hessenberg matrix, using the shifts with sum `s` and product `t`
+fn francis_step<const T: usize>(a: &mut [[f64; T]; T], lo: usize, hi: usize, s: f64, t: f64) {
+	let mut x = a[lo][lo] * a[lo][lo] + a[lo][lo + 1] * a[lo + 1][lo] - s * a[lo][lo] + t;
+	let mut y = a[lo + 1][lo] * (a[lo][lo] + a[lo + 1][lo + 1] - s);
+	let mut z = a[lo + 1][lo] * a[lo + 2][lo + 1];
+	for k in lo..hi - 1 {
+		if let Some(u) = real_householder(&[x, y, z]) {
+			reflect(a, &u, k, k.saturating_sub(1).max(lo)..=hi, lo, (k + 3).min(hi));
+		}
+		if k > lo {
+			a[k + 1][k - 1] = 0.;
+			a[k + 2][k - 1] = 0.;
+		}
+		x = a[k + 1][k];
+		y = a[k + 2][k];
+		if k + 3 <= hi {
+			z = a[k + 3][k]
+		}
+	}
+	if let Some(u) = real_householder(&[x, y]) {
+		reflect(a, &u, hi - 1, hi - 2..=hi, lo, hi);
+	}
+	a[hi][hi - 2] = 0.;
+}
+
+/// Calculate the eigenvalues of a real matrix
+///
+/// The matrix is reduced to upper Hessenberg form and then the implicitly shifted Francis
+/// double-shift QR algorithm is used, which keeps every value real by using complex conjugate
+/// pairs of shifts together. Returns `None` if this does not converge.
+pub(crate) fn real_eigenvalues<const T: usize>(a: [[f64; T]; T]) -> Option<Vec<Complex<f64>>> {
+	let mut complex = a.map(|row| row.map(Complex::from_real));
+	hessenberg(&mut complex);
+	let mut a = complex.map(|row| row.map(|z| z.real));
+	let norm = a.iter().flatten().map(|v| v.abs()).fold(0., f64::max);
+	let mut out = Vec::with_capacity(T);
+	let mut hi = T;
+	let mut iterations = 0;
+	while hi > 0 {
+		let last = hi - 1;
+		let lo = (1..=last).rev().find(|l| {
+			let diagonal = a[*l][*l].abs() + a[l - 1][l - 1].abs();
+			a[*l][l - 1].abs() <= f64::EPSILON * if diagonal == 0. { norm } else { diagonal }
+		}).unwrap_or(0);
+		if lo > 0 {
+			a[lo][lo - 1] = 0.
+		}
+		if lo == last {
+			out.push(Complex::from_real(a[last][last]));
+			hi -= 1;
+			iterations = 0;
+			continue
+		}
+		if lo + 1 == last {
+			out.extend(real_pair(a[lo][lo], a[lo][last], a[last][lo], a[last][last]));
+			hi -= 2;
+			iterations = 0;
+			continue
+		}
+		iterations += 1;
+		if iterations > MAX_ITERATIONS * T {
+			return None
+		}
+		let (s, t) = if iterations % 10 == 0 {
+			let shift = a[last][last] + 0.75 * (a[last][last - 1].abs() + a[last - 1][last - 2].abs());
+			(2. * shift, shift * shift + 0.4375 * a[last][last - 1].powi(2))
+		} else {
+			(
+				a[last - 1][last - 1] + a[last][last],
+				a[last - 1][last - 1] * a[last][last] - a[last - 1][last] * a[last][last - 1]
+			)
+		};
+		francis_step(&mut a, lo, last, s, t);
+	}
+	Some(out)
+}
+
+/// An eigenvalue with its multiplicity and a basis for its eigenspace
+pub(crate) type Eigenspace<const T: usize> = (Complex<f64>, usize, Vec<[Complex<f64>; T]>);
+
+/// Group eigenvalues together and find a basis for each eigenspace
+///
+/// Eigenvalues closer together than `tolerance` to the smallest in the group are treated as
+/// repeated. Returns `(value, multiplicity, basis)` for each group in ascending order of the real
+/// part, where `value` is the mean of the group. The basis can be smaller than the multiplicity if
+/// the matrix is defective.
+pub(crate) fn eigenspaces<const T: usize>(a: [[Complex<f64>; T]; T], mut values: Vec<Complex<f64>>, tolerance: f64) -> Vec<Eigenspace<T>> {
+	values.sort_by(|a, b| a.real.total_cmp(&b.real).then(a.imaginary.total_cmp(&b.imaginary)));
+	let mut out = Vec::new();
+	while let Some(first) = values.first().copied() {
+		let (repeated, rest): (Vec<Complex<f64>>, Vec<Complex<f64>>) = values.iter().partition(|v| (**v - first).abs() <= tolerance);
+		values = rest;
+		let value = repeated.iter().fold(zero(), |a, b| a + *b) / Complex::from_real(repeated.len() as f64);
+		let mut shifted = a;
+		for (i, row) in shifted.iter_mut().enumerate() {
+			row[i] -= value
+		}
+		out.push((value, repeated.len(), null_space(shifted, tolerance)));
+	}
+	out
+}
+
+/// Calculate the eigenvalues and eigenvectors of a real symmetric matrix
+///
+/// This uses cyclic Jacobi rotations, each of which zeroes one off-diagonal value, until the
+/// off-diagonal values are negligible. Returns the eigenvalues in ascending order and an orthogonal
+/// matrix with the matching eigenvectors as columns, each scaled so its largest value is positive.
+/// Returns `None` if this does not converge.
+pub(crate) fn jacobi<const T: usize>(mut a: [[f64; T]; T]) -> Option<([f64; T], [[f64; T]; T])> {
+	for (i, j) in (0..T).flat_map(|i| (i + 1..T).map(move |j| (i, j))) {
+		let mean = (a[i][j] + a[j][i]) / 2.;
+		a[i][j] = mean;
+		a[j][i] = mean;
+	}
+	let mut v = [[0.; T]; T];
+	for (i, row) in v.iter_mut().enumerate() {
+		row[i] = 1.
+	}
+	let norm = a.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+	let mut converged = false;
+	for _ in 0..MAX_SWEEPS {
+		let off = (0..T).map(|i| (i + 1..T).map(|j| a[i][j] * a[i][j]).sum::<f64>()).sum::<f64>().sqrt();
+		if off <= f64::EPSILON * norm {
+			converged = true;
+			break
+		}
+		for p in 0..T {
+			for q in p + 1..T {
+				if a[p][q] == 0. {
+					continue
+				}
+				let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+				let t = 1f64.copysign(theta) / (theta.abs() + theta.hypot(1.));
+				let c = 1. / t.hypot(1.);
+				let s = t * c;
+				for row in a.iter_mut().chain(v.iter_mut()) {
+					let (x, y) = (row[p], row[q]);
+					row[p] = c * x - s * y;
+					row[q] = s * x + c * y;
+				}
+				let (top, bottom) = a.split_at_mut(q);
+				for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+					(*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+				}
+				a[p][q] = 0.;
+				a[q][p] = 0.;
+			}
+		}
+	}
+	if !converged {
+		return None
+	}
+	let mut order = [0; T];
+	for (i, o) in order.iter_mut().enumerate() {
+		*o = i
+	}
+	order.sort_by(|i, j| a[*i][*i].total_cmp(&a[*j][*j]));
+	let values = order.map(|i| a[i][i]);
+	let mut vectors = [[0.; T]; T];
+	for (new, old) in order.iter().enumerate() {
+		let largest = (0..T).map(|k| v[k][*old]).fold(0., |a: f64, b| if b.abs() > a.abs() { b } else { a });
+		let sign = if largest < 0. { -1. } else { 1. };
+		for k in 0..T {
+			vectors[k][new] = sign * v[k][*old]
+		}
+	}
+	Some((values, vectors))
+}
+
+/// Return a basis for the null space of a matrix
+///
+/// The matrix is reduced to reduced row echelon form using partial pivoting. Any column without a
+/// pivot larger than `tolerance` is a free column and gives one basis vector.
+pub(crate) fn null_space<const T: usize>(mut a: [[Complex<f64>; T]; T], tolerance: f64) -> Vec<[Complex<f64>; T]> {
+	let mut pivots = Vec::new();
+	for col in 0..T {
+		let row = pivots.len();
+		if row == T {
+			break
+		}
+		let pivot = (row + 1..T).fold(row, |p, r| if a[r][col].abs() > a[p][col].abs() { r } else { p });
+		if a[pivot][col].abs() <= tolerance {
+			continue
+		}
+		a.swap(row, pivot);
+		let scale = a[row][col];
+		for value in a[row].iter_mut() {
+			*value /= scale
+		}
+		let pivot_row = a[row];
+		for (r, values) in a.iter_mut().enumerate() {
+			let factor = values[col];
+			if r != row && factor.abs() != 0. {
+				for (value, pivot) in values.iter_mut().zip(pivot_row.iter()) {
+					*value -= factor * *pivot
+				}
+			}
+		}
+		pivots.push(col);
+	}
+	(0..T).filter(|c| !pivots.contains(c)).map(|free| {
+		let mut out = [zero(); T];
+		out[free] = Complex::from_real(1.);
+		for (r, c) in pivots.iter().enumerate() {
+			out[*c] = -a[r][free]
+		}
+		out
+	}).collect()
+}
+
+/// Invert a matrix using Gauss-Jordan elimination with partial pivoting
+///
+/// Returns `None` if any pivot is no larger than `tolerance`
+pub(crate) fn inverse<const T: usize>(mut a: [[Complex<f64>; T]; T], tolerance: f64) -> Option<[[Complex<f64>; T]; T]> {
+	let mut out = [[zero(); T]; T];
+	for (i, row) in out.iter_mut().enumerate() {
+		row[i] = Complex::from_real(1.)
+	}
+	for col in 0..T {
+		let pivot = (col + 1..T).fold(col, |p, r| if a[r][col].abs() > a[p][col].abs() { r } else { p });
+		if a[pivot][col].abs() <= tolerance {
+			return None
+		}
+		a.swap(col, pivot);
+		out.swap(col, pivot);
+		let scale = a[col][col];
+		for j in 0..T {
+			a[col][j] /= scale;
+			out[col][j] /= scale;
+		}
+		for r in 0..T {
+			let factor = a[r][col];
+			if r != col && factor.abs() != 0. {
+				for j in 0..T {
+					a[r][j] -= factor * a[col][j];
+					out[r][j] -= factor * out[col][j];
+				}
+			}
+		}
+	}
+	Some(out)
+}
+
+/// A runtime sized matrix stored as a list of rows
+pub(crate) type Rows = Vec<Vec<Complex<f64>>>;
+
+/// Return the Householder vector `v` that reflects `x` onto a multiple of the first axis, along with
+/// `vᴴv`. Returns `None` if `x` is zero
+fn householder(x: &[Complex<f64>]) -> Option<(Vec<Complex<f64>>, Complex<f64>)> {
+	let norm = x.iter().map(|z| z.abs().powi(2)).sum::<f64>().sqrt();
+	if norm == 0. {
+		return None
+	}
+	let phase = if x[0].abs() == 0. { Complex::from_real(1.) } else { x[0] / Complex::from_real(x[0].abs()) };
+	let mut v = x.to_vec();
+	v[0] += phase * Complex::from_real(norm);
+	let length = Complex::from_real(v.iter().map(|z| z.abs().powi(2)).sum::<f64>());
+	Some((v, length))
+}
+
+/// Multiply columns `i` and `j` of `m` by the rotation `[[c, -s], [s, c]]`
+fn rotate_columns(m: &mut [Vec<Complex<f64>>], i: usize, j: usize, c: f64, s: f64) {
+	let (c, s) = (Complex::from_real(c), Complex::from_real(s));
+	for row in m.iter_mut() {
+		let (x, y) = (row[i], row[j]);
+		row[i] = c * x + s * y;
+		row[j] = c * y - s * x;
+	}
+}
+
+/// Calculate the singular value decomposition of an `m`×`n` matrix with `m≥n`
+///
+/// Returns `(U, s, V)` such that `A = U diag(s) Vᴴ` where `U` is `m`×`m`, `V` is `n`×`n`, and
+/// the singular values `s` are in decreasing order. The matrix is reduced to a real bidiagonal
+/// matrix with Golub–Kahan bidiagonalisation and then the implicitly shifted QR algorithm is used
+/// on the bidiagonal matrix. If `vectors` is false, `U` and `V` are not calculated and are empty.
+/// Returns `None` if this does not converge.
+pub(crate) fn svd(mut b: Rows, vectors: bool) -> Option<(Rows, Vec<f64>, Rows)> {
+	let (m, n) = (b.len(), b.first().map_or(0, |row| row.len()));
+	let identity = |size: usize| (0..size).map(|i| {
+		let mut row = vec![zero(); size];
+		row[i] = Complex::from_real(1.);
+		row
+	}).collect::<Rows>();
+	let (mut u, mut v) = if vectors { (identity(m), identity(n)) } else { (Vec::new(), Vec::new()) };
+	let two = Complex::from_real(2.);
+	for k in 0..n {
+		let column = (k..m).map(|i| b[i][k]).collect::<Vec<Complex<f64>>>();
+		if let Some((h, length)) = householder(&column) {
+			let s = (k..n).map(|j| (k..m).fold(zero(), |a, i| a + h[i - k].conj() * b[i][j]) * two / length).collect::<Vec<Complex<f64>>>();
+			for (row, hi) in b.iter_mut().skip(k).zip(h.iter()) {
+				for (value, sj) in row.iter_mut().skip(k).zip(s.iter()) {
+					*value -= *hi * *sj
+				}
+			}
+			for row in u.iter_mut() {
+				let s = (k..m).fold(zero(), |a, i| a + row[i] * h[i - k]) * two / length;
+				for i in k..m {
+					row[i] -= s * h[i - k].conj()
+				}
+			}
+		}
+		if b[k][k].abs() != 0. {
+			let phase = b[k][k] / Complex::from_real(b[k][k].abs());
+			for value in b[k].iter_mut().skip(k) {
+				*value *= phase.conj()
+			}
+			for row in u.iter_mut() {
+				row[k] *= phase
+			}
+		}
+		if k + 1 < n {
+			let row = (k + 1..n).map(|j| b[k][j].conj()).collect::<Vec<Complex<f64>>>();
+			if let Some((h, length)) = householder(&row) {
+				for row in b.iter_mut().skip(k) {
+					let s = row.iter().skip(k + 1).zip(h.iter()).fold(zero(), |a, (x, y)| a + *x * *y) * two / length;
+					for (value, hj) in row.iter_mut().skip(k + 1).zip(h.iter()) {
+						*value -= s * hj.conj()
+					}
+				}
+				for row in v.iter_mut() {
+					let s = (k + 1..n).fold(zero(), |a, j| a + row[j] * h[j - k - 1]) * two / length;
+					for j in k + 1..n {
+						row[j] -= s * h[j - k - 1].conj()
+					}
+				}
+			}
+			if b[k][k + 1].abs() != 0. {
+				let phase = b[k][k + 1] / Complex::from_real(b[k][k + 1].abs());
+				for row in b.iter_mut().skip(k) {
+					row[k + 1] *= phase.conj()
+				}
+				for row in v.iter_mut() {
+					row[k + 1] *= phase.conj()
+				}
+			}
+		}
+	}
+	let mut d = (0..n).map(|k| b[k][k].real).collect::<Vec<f64>>();
+	let mut e = (0..n.saturating_sub(1)).map(|k| b[k][k + 1].real).collect::<Vec<f64>>();
+	let norm = (0..n).map(|k| d[k].abs() + e.get(k).map_or(0., |v| v.abs())).fold(0., f64::max);
+	let tolerance = f64::EPSILON * norm;
+	let mut hi = n.saturating_sub(1);
+	let mut iterations = 0;
+	while hi > 0 {
+		if e[hi - 1].abs() <= tolerance {
+			e[hi - 1] = 0.;
+			hi -= 1;
+			continue
+		}
+		iterations += 1;
+		if iterations > MAX_ITERATIONS * n * n {
+			return None
+		}
+		let lo = (0..hi - 1).rev().find(|i| e[*i].abs() <= tolerance).map_or(0, |i| {
+			e[i] = 0.;
+			i + 1
+		});
+		if let Some(i) = (lo..hi).find(|i| d[*i].abs() <= tolerance) {
+			d[i] = 0.;
+			let mut f = e[i];
+			e[i] = 0.;
+			for j in i + 1..=hi {
+				let r = d[j].hypot(f);
+				let (c, s) = (d[j] / r, f / r);
+				d[j] = r;
+				if j < hi {
+					f = -s * e[j];
+					e[j] *= c;
+				}
+				rotate_columns(&mut u, j, i, c, s);
+			}
+			continue
+		}
+		if d[hi].abs() <= tolerance {
+			d[hi] = 0.;
+			let mut f = e[hi - 1];
+			e[hi - 1] = 0.;
+			for j in (lo..hi).rev() {
+				let r = d[j].hypot(f);
+				let (c, s) = (d[j] / r, f / r);
+				d[j] = r;
+				if j > lo {
+					f = -s * e[j - 1];
+					e[j - 1] *= c;
+				}
+				rotate_columns(&mut v, j, hi, c, s);
+			}
+			continue
+		}
+		let t11 = d[hi - 1].powi(2) + if hi - 1 > lo { e[hi - 2].powi(2) } else { 0. };
+		let t12 = d[hi - 1] * e[hi - 1];
+		let t22 = d[hi].powi(2) + e[hi - 1].powi(2);
+		let half = (t11 - t22) / 2.;
+		let root = half.hypot(t12);
+		let shift = t22 - t12 * t12 / (half + if half < 0. { -root } else { root });
+		let shift = if shift.is_finite() { shift } else { t22 };
+		let mut y = d[lo].powi(2) - shift;
+		let mut z = d[lo] * e[lo];
+		for k in lo..hi {
+			let r = y.hypot(z);
+			let (c, s) = if r == 0. { (1., 0.) } else { (y / r, z / r) };
+			if k > lo {
+				e[k - 1] = r
+			}
+			let (dk, ek) = (d[k], e[k]);
+			d[k] = c * dk + s * ek;
+			e[k] = c * ek - s * dk;
+			let bulge = s * d[k + 1];
+			d[k + 1] *= c;
+			rotate_columns(&mut v, k, k + 1, c, s);
+			let r = d[k].hypot(bulge);
+			let (c, s) = if r == 0. { (1., 0.) } else { (d[k] / r, bulge / r) };
+			d[k] = r;
+			let (ek, dk1) = (e[k], d[k + 1]);
+			e[k] = c * ek + s * dk1;
+			d[k + 1] = c * dk1 - s * ek;
+			y = e[k];
+			if k + 1 < hi {
+				z = s * e[k + 1];
+				e[k + 1] *= c;
+			}
+			rotate_columns(&mut u, k, k + 1, c, s);
+		}
+	}
+	for k in 0..n {
+		if d[k] < 0. {
+			d[k] = -d[k];
+			for row in v.iter_mut() {
+				row[k] = -row[k]
+			}
+		}
+	}
+	let mut order = (0..n).collect::<Vec<usize>>();
+	order.sort_by(|a, b| d[*b].total_cmp(&d[*a]));
+	let values = order.iter().map(|i| d[*i]).collect();
+	let reorder = |m: Rows| m.into_iter().map(|row| {
+		let mut out = row.clone();
+		for (new, old) in order.iter().enumerate() {
+			out[new] = row[*old]
+		}
+		out
+	}).collect::<Rows>();
+	Some((reorder(u), values, reorder(v)))
+}
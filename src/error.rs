@@ -0,0 +1,57 @@
+//! Error type for fallible matrix and polynomial operations
+
+use std::fmt::{Display, Formatter};
+
+/// Errors returned by fallible matrix and polynomial operations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+	/// The matrix is singular, or close enough to singular that it's treated as such
+	SingularMatrix,
+	/// Elimination without pivoting found a zero pivot
+	ZeroPivot {
+		/// Row of the pivot
+		row: usize,
+		/// Column of the pivot
+		col: usize
+	},
+	/// A value can't be represented in the target dtype
+	ConversionOverflow,
+	/// The operation isn't supported for a polynomial of this degree
+	UnsupportedDegree(usize),
+	/// The matrix doesn't have the expected number of rows and columns
+	DimensionMismatch {
+		/// Expected `(rows, columns)`
+		expected: (usize, usize),
+		/// Actual `(rows, columns)`
+		found: (usize, usize)
+	},
+	/// An iterative algorithm did not converge
+	NoConvergence,
+	/// A division in an integer dtype had a remainder, so the exact result can't be represented
+	InexactDivision,
+	/// An integer calculation overflowed the dtype
+	Overflow,
+	/// The matrix isn't symmetric, or Hermitian for complex dtypes
+	NotSymmetric,
+	/// The matrix isn't positive-definite
+	NotPositiveDefinite
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Error::SingularMatrix => write!(f, "matrix is singular"),
+			Error::ZeroPivot { row, col } => write!(f, "zero pivot at ({}, {})", row, col),
+			Error::ConversionOverflow => write!(f, "value can't be represented in the target dtype"),
+			Error::UnsupportedDegree(degree) => write!(f, "unsupported polynomial degree {}", degree),
+			Error::DimensionMismatch { expected, found } => write!(f, "expected a {}×{} matrix but found a {}×{} matrix", expected.0, expected.1, found.0, found.1),
+			Error::NoConvergence => write!(f, "iteration did not converge"),
+			Error::InexactDivision => write!(f, "division is not exact in the dtype"),
+			Error::Overflow => write!(f, "value overflowed the dtype"),
+			Error::NotSymmetric => write!(f, "matrix is not symmetric"),
+			Error::NotPositiveDefinite => write!(f, "matrix is not positive-definite")
+		}
+	}
+}
+
+impl std::error::Error for Error {}
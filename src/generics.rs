@@ -5,9 +5,9 @@
 
 use std::fmt::Debug;
 use crate::prelude::Matrix;
-use conv::{ConvUtil, ValueFrom};
+use crate::scalar::{One, Zero};
 
-impl<const T: usize, L: Copy + Debug> Matrix<T, T, L> where L: ValueFrom<isize> {
+impl<const T: usize, L: Copy + Debug> Matrix<T, T, L> where L: Zero + One {
 	/// Generate an identity matrix of a specified size.
 	///
 	/// This will only work for a `Matrix<T, T, L>` matrix
@@ -19,20 +19,20 @@ impl<const T: usize, L: Copy + Debug> Matrix<T, T, L> where L: ValueFrom<isize>
 	pub fn identity() -> Self {
 		let mut out = Self::empty();
 		for i in 0..T {
-			out[(i, i)] = 1.value_as().unwrap();
+			out[(i, i)] = L::one();
 		}
 		out
 	}
 }
 
-impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> where L: ValueFrom<isize> {
+impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> where L: Zero {
 	/// Generate an empty matrix of a specified size
 	/// ```
 	/// # use lineas::prelude::Matrix;
 	/// use lineas::generics;
-	/// assert_eq!(Matrix::new([[0, 0, 0], [0, 0, 0]]), Matrix::<3, 2, _>::empty())
+	/// assert_eq!(Matrix::new([[0, 0, 0], [0, 0, 0]]), Matrix::<2, 3, _>::empty())
 	/// ```
 	pub fn empty() -> Self {
-		Self::new([[0.value_as().unwrap(); N]; T])
+		Self::new([[L::zero(); N]; T])
 	}
 }
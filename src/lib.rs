@@ -6,7 +6,7 @@
 //! algorithms to make your code run as fast as possible.
 //!
 //! ## Making a matrix
-//!	Matrices need you to specify the size of the matrix (rows×columns) and the data type in the
+//! Matrices need you to specify the size of the matrix (rows×columns) and the data type in the
 //! matrix.
 //! ```
 //! # use lineas::Matrix;
@@ -33,7 +33,21 @@ pub mod prelude;
 pub use prelude::*;
 pub mod decompose;
 pub mod generics;
+pub mod scalar;
+mod error;
+pub use error::Error;
 mod ops;
 mod complex_ops;
+mod complex_functions;
+mod rational;
+mod modp;
+mod eigen;
+mod subspaces;
+mod norms;
+mod vectors;
+mod blocks;
+mod dense;
+mod dmatrix;
+mod boxed;
 pub mod polynomials;
 mod display;
@@ -0,0 +1,249 @@
+//! Implementations for the prime field [`ModP`] dtype
+
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use conv::{NoError, ValueFrom};
+use crate::ModP;
+
+/// Return `base^exponent mod modulus`
+const fn pow_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+	let (mut out, mut base, mut exponent) = (1, base as u128 % modulus as u128, exponent);
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			out = out * base % modulus as u128
+		}
+		base = base * base % modulus as u128;
+		exponent >>= 1
+	}
+	out as u64
+}
+
+/// Check if `n` is prime with the Miller-Rabin test
+///
+/// Testing the first twelve primes as witnesses is enough to be exact for every `u64`
+const fn is_prime(n: u64) -> bool {
+	const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+	if n < 2 {
+		return false
+	}
+	let mut i = 0;
+	while i < WITNESSES.len() {
+		if n % WITNESSES[i] == 0 {
+			return n == WITNESSES[i]
+		}
+		i += 1
+	}
+	let (mut d, mut s) = (n - 1, 0);
+	while d % 2 == 0 {
+		d /= 2;
+		s += 1
+	}
+	let mut i = 0;
+	while i < WITNESSES.len() {
+		let mut x = pow_mod(WITNESSES[i], d, n);
+		let mut r = 1;
+		while x != 1 && x != n - 1 && r < s {
+			x = pow_mod(x, 2, n);
+			r += 1
+		}
+		if x != n - 1 && (x != 1 || r > 1) {
+			return false
+		}
+		i += 1
+	}
+	true
+}
+
+impl<const P: u64> ModP<P> {
+	/// Make a new value, which is reduced modulo `P`
+	///
+	/// This will fail to compile if `P` isn't prime
+	/// ```
+	/// # use lineas::ModP;
+	/// assert_eq!(ModP::<7>::new(10).value(), 3)
+	/// ```
+	/// ```compile_fail
+	/// # use lineas::ModP;
+	/// let _ = ModP::<6>::new(1);
+	/// ```
+	pub fn new(value: u64) -> Self {
+		const { assert!(is_prime(P), "The modulus must be a prime") }
+		Self(value % P)
+	}
+	
+	/// Return the value as an integer from `0` to `P-1`
+	pub fn value(&self) -> u64 {
+		self.0
+	}
+	
+	/// Raise the value to the power `exponent` by repeated squaring
+	pub fn pow(&self, exponent: u64) -> Self {
+		let mut out = Self::new(1);
+		let mut base = *self;
+		let mut exponent = exponent;
+		while exponent > 0 {
+			if exponent & 1 == 1 {
+				out *= base
+			}
+			base *= base;
+			exponent >>= 1
+		}
+		out
+	}
+	
+	/// Return the multiplicative inverse, or `None` if the value is zero
+	///
+	/// This uses the extended Euclidean algorithm to find `x` such that `ax ≡ 1 (mod P)`
+	/// ```
+	/// # use lineas::ModP;
+	/// assert_eq!(ModP::<7>::new(3).try_inverse(), Some(ModP::new(5)));
+	/// assert_eq!(ModP::<7>::new(0).try_inverse(), None)
+	/// ```
+	pub fn try_inverse(&self) -> Option<Self> {
+		let (mut r, mut new_r) = (P as i128, self.0 as i128);
+		let (mut t, mut new_t) = (0i128, 1i128);
+		while new_r != 0 {
+			let quotient = r / new_r;
+			(r, new_r) = (new_r, r - quotient * new_r);
+			(t, new_t) = (new_t, t - quotient * new_t);
+		}
+		if r != 1 {
+			return None
+		}
+		Some(Self(t.rem_euclid(P as i128) as u64))
+	}
+	
+	/// Return a square root of the value, or `None` if it isn't a square modulo `P`
+	///
+	/// This uses the Tonelli-Shanks algorithm and returns the smaller of the two roots
+	/// ```
+	/// # use lineas::ModP;
+	/// assert_eq!(ModP::<13>::new(10).try_sqrt(), Some(ModP::new(6)));
+	/// assert_eq!(ModP::<13>::new(5).try_sqrt(), None)
+	/// ```
+	pub fn try_sqrt(&self) -> Option<Self> {
+		if self.0 == 0 || P == 2 {
+			return Some(*self)
+		}
+		if self.pow((P - 1) / 2).0 != 1 {
+			return None
+		}
+		let (mut q, mut s) = (P - 1, 0);
+		while q % 2 == 0 {
+			q /= 2;
+			s += 1
+		}
+		let non_residue = (2..P).map(Self::new).find(|z| z.pow((P - 1) / 2).0 == P - 1)?;
+		let (mut m, mut c, mut t, mut root) = (s, non_residue.pow(q), self.pow(q), self.pow(q.div_ceil(2)));
+		while t.0 != 1 {
+			let mut i = 1;
+			let mut square = t * t;
+			while square.0 != 1 {
+				square *= square;
+				i += 1
+			}
+			let b = c.pow(1 << (m - i - 1));
+			m = i;
+			c = b * b;
+			t *= c;
+			root *= b
+		}
+		Some(if root.0 <= P - root.0 { root } else { -root })
+	}
+}
+
+impl<const P: u64> Add for ModP<P> {
+	type Output = Self;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+	}
+}
+
+impl<const P: u64> AddAssign for ModP<P> {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs
+	}
+}
+
+impl<const P: u64> Sub for ModP<P> {
+	type Output = Self;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		if self.0 >= rhs.0 { Self(self.0 - rhs.0) } else { Self(P - (rhs.0 - self.0)) }
+	}
+}
+
+impl<const P: u64> SubAssign for ModP<P> {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs
+	}
+}
+
+impl<const P: u64> Neg for ModP<P> {
+	type Output = Self;
+	
+	fn neg(self) -> Self::Output {
+		if self.0 == 0 { self } else { Self(P - self.0) }
+	}
+}
+
+impl<const P: u64> Mul for ModP<P> {
+	type Output = Self;
+	
+	fn mul(self, rhs: Self) -> Self::Output {
+		Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+	}
+}
+
+impl<const P: u64> MulAssign for ModP<P> {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs
+	}
+}
+
+impl<const P: u64> Div for ModP<P> {
+	type Output = Self;
+	
+	/// Divide two values
+	///
+	/// This will panic if `rhs` is zero
+	fn div(self, rhs: Self) -> Self::Output {
+		Mul::mul(self, rhs.try_inverse().expect("Cannot divide by zero"))
+	}
+}
+
+impl<const P: u64> DivAssign for ModP<P> {
+	fn div_assign(&mut self, rhs: Self) {
+		*self = *self / rhs
+	}
+}
+
+/// Convert primitive integers into a [`ModP`], reducing negative values to `0` to `P-1`
+macro_rules! modp_from {
+	($($t:ty),*) => {$(
+		impl<const P: u64> ValueFrom<$t> for ModP<P> {
+			type Err = NoError;
+			
+			fn value_from(src: $t) -> Result<Self, Self::Err> {
+				Ok(Self::new((src as i128).rem_euclid(P as i128) as u64))
+			}
+		}
+	)*};
+}
+
+modp_from!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<const P: u64> Debug for ModP<P> {
+	/// Format the value the same way as [`Display`]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Debug::fmt(&self.0, f)
+	}
+}
+
+impl<const P: u64> Display for ModP<P> {
+	/// Format the value as an integer from `0` to `P-1`
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
@@ -0,0 +1,121 @@
+//! Matrix norms and the condition number
+//!
+//! Every norm is returned as an `f64` whatever the dtype. The entrywise and induced 1 and ∞ norms
+//! are computed directly, while the spectral and nuclear norms need the singular values and so can
+//! fail to converge. Dtypes without a norm, such as [`ModP`][crate::ModP], fail to compile.
+
+use crate::decompose::singular_value_decomposition;
+use crate::prelude::Matrix;
+use crate::scalar::{assert_normed, Scalar};
+use crate::Error;
+
+impl<const T: usize, const N: usize, L: Scalar> Matrix<T, N, L> {
+	/// Return the largest absolute value in the matrix
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, -7], [3, 4]]).max_abs(), 7.)
+	/// ```
+	pub fn max_abs(&self) -> f64 {
+		self.0.iter().flatten().map(|v| v.modulus()).fold(0., f64::max)
+	}
+	
+	/// Return the Frobenius norm of the matrix
+	///
+	/// This is the square root of the sum of the squares of the absolute values in the matrix
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, -2], [2, 4]]).norm_frobenius(), 5.)
+	/// ```
+	pub fn norm_frobenius(&self) -> f64 {
+		assert_normed::<L>();
+		self.0.iter().flatten().map(|v| v.modulus().powi(2)).sum::<f64>().sqrt()
+	}
+	
+	/// Return the 1-norm of the matrix
+	///
+	/// This is the largest sum of the absolute values in a column
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, -2], [3, 4]]).norm_1(), 6.)
+	/// ```
+	pub fn norm_1(&self) -> f64 {
+		assert_normed::<L>();
+		self.max_column_sum()
+	}
+	
+	/// Return the largest sum of the moduli in a column, which is the 1-norm for dtypes with a norm
+	pub(crate) fn max_column_sum(&self) -> f64 {
+		(0..N).map(|j| (0..T).map(|i| self[(i, j)].modulus()).sum::<f64>()).fold(0., f64::max)
+	}
+	
+	/// Return the infinity norm of the matrix
+	///
+	/// This is the largest sum of the absolute values in a row
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, -2], [3, 4]]).norm_inf(), 7.)
+	/// ```
+	pub fn norm_inf(&self) -> f64 {
+		assert_normed::<L>();
+		self.0.iter().map(|row| row.iter().map(|v| v.modulus()).sum::<f64>()).fold(0., f64::max)
+	}
+	
+	/// Return the singular values of the matrix in decreasing order
+	///
+	/// There are `min(T, N)` singular values, unlike
+	/// [`singular_values`][crate::decompose::SVDecompose::singular_values] which is padded to `N`
+	fn singular_value_list(&self) -> Result<Vec<f64>, Error> {
+		let (_, values, _) = singular_value_decomposition(self, false)?;
+		Ok(values.into_iter().take(T.min(N)).collect())
+	}
+	
+	/// Return the spectral norm of the matrix
+	///
+	/// This is the largest singular value, which is the most a vector can be stretched by the
+	/// matrix. It is calculated with the [singular value decomposition][crate::decompose::SVDecompose],
+	/// and returns [`Error::NoConvergence`] if that does not converge, which is very rare.
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert!((Matrix::new([[3, 0], [4, 5]]).norm_2().unwrap() - 45f64.sqrt()).abs() < 1e-14)
+	/// ```
+	pub fn norm_2(&self) -> Result<f64, Error> {
+		Ok(self.singular_value_list()?.first().copied().unwrap_or(0.))
+	}
+	
+	/// Return the nuclear norm of the matrix
+	///
+	/// This is the sum of the singular values, which can fail to converge like
+	/// [`norm_2`][Matrix::norm_2]
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert!((Matrix::new([[3, 0], [4, 5]]).norm_nuclear().unwrap() - 4. * 5f64.sqrt()).abs() < 1e-14)
+	/// ```
+	pub fn norm_nuclear(&self) -> Result<f64, Error> {
+		Ok(self.singular_value_list()?.iter().sum())
+	}
+	
+	/// Return the condition number of the matrix in the 2-norm
+	///
+	/// This is the ratio of the largest and smallest singular values, which measures how much
+	/// errors in `b` can grow when solving `Ax = b`. Roughly, `log₁₀` of the condition number is
+	/// the number of significant digits that can be lost. Singular matrices have an infinite
+	/// condition number.
+	///
+	/// This calculates the condition number exactly with the singular values, which can fail to
+	/// converge like [`norm_2`][Matrix::norm_2]. If you already have a [`PLUFactorisation`][crate::decompose::PLUFactorisation]
+	/// of the matrix, [`condition_estimate`][crate::decompose::PLUFactorisation::condition_estimate]
+	/// is much cheaper.
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert!((Matrix::new([[3, 0], [4, 5]]).condition_number().unwrap() - 3.).abs() < 1e-14);
+	/// assert_eq!(Matrix::new([[1, 2], [2, 4]]).condition_number(), Ok(f64::INFINITY))
+	/// ```
+	pub fn condition_number(&self) -> Result<f64, Error> {
+		let values = self.singular_value_list()?;
+		Ok(match (values.first(), values.last()) {
+			(_, Some(smallest)) if *smallest == 0. => f64::INFINITY,
+			(Some(largest), Some(smallest)) => largest / smallest,
+			_ => 0.
+		})
+	}
+}
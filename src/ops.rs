@@ -1,11 +1,17 @@
 //! Operation implementations for the matrix
 
-use crate::prelude::{Matrix, Vector};
+use crate::prelude::{ColVector, Matrix, RowVector, Vector};
 use std::fmt::Debug;
-use std::ops::{Add, Sub, Index, AddAssign, IndexMut, SubAssign, Mul, MulAssign, Neg};
+use std::ops::{Add, Div, Sub, Index, AddAssign, IndexMut, SubAssign, Mul, MulAssign, Neg};
 use conv::{ConvUtil, ValueFrom, ValueInto};
 use itertools::Itertools;
 use crate::Complex;
+use crate::polynomials::Polynomial;
+use crate::scalar::{Ring, Scalar, Zero};
+use crate::decompose::PLUFactorisation;
+use crate::eigen;
+use crate::dense::Dense;
+use crate::Error;
 
 impl<const T: usize, const N: usize, L: Copy + Debug> Add for Matrix<T, N, L> where L: Add + AddAssign {
 	type Output = Self;
@@ -19,8 +25,8 @@ impl<const T: usize, const N: usize, L: Copy + Debug> Add for Matrix<T, N, L> wh
 
 impl<const T: usize, const N: usize, L: Copy + Debug> AddAssign for Matrix<T, N, L> where L: Add + AddAssign {
 	fn add_assign(&mut self, rhs: Self) {
-		for i in 0..N {
-			for n in 0..T {
+		for i in 0..T {
+			for n in 0..N {
 				self[(i, n)] += rhs[(i, n)]
 			}
 		}
@@ -39,15 +45,15 @@ impl<const T: usize, const N: usize, L: Copy + Debug> Sub for Matrix<T, N, L> wh
 
 impl<const T: usize, const N: usize, L: Copy + Debug> SubAssign for Matrix<T, N, L> where L: Sub + SubAssign {
 	fn sub_assign(&mut self, rhs: Self) {
-		for i in 0..N {
-			for n in 0..T {
+		for i in 0..T {
+			for n in 0..N {
 				self[(i, n)] -= rhs[(i, n)]
 			}
 		}
 	}
 }
 
-impl<const T: usize, const N: usize, const P: usize, L: Copy + Debug + ValueFrom<isize>> Mul<Matrix<N, P, L>> for Matrix<T, N, L> where L: Add<Output=L> + AddAssign + Mul<Output=L> + ValueFrom<isize> {
+impl<const T: usize, const N: usize, const P: usize, L: Copy + Debug> Mul<Matrix<N, P, L>> for Matrix<T, N, L> where L: Zero + AddAssign + Mul<Output=L> {
 	type Output = Matrix<T, P, L>;
 	
 	/// Multiply two matrices together
@@ -58,47 +64,153 @@ impl<const T: usize, const N: usize, const P: usize, L: Copy + Debug + ValueFrom
 	/// Note that matrix multiplication is not commutative; i.e. `A*B` is not necessarily the same
 	/// as `B * A`.
 	fn mul(self, rhs: Matrix<N, P, L>) -> Self::Output {
-		let mut data = [[0.value_as().unwrap(); P]; T];
-		for i in 0..T {
-			for j in 0..P {
-				let mut res: L = 0.value_as().unwrap();
+		let mut data = [[L::zero(); P]; T];
+		for (i, row) in data.iter_mut().enumerate() {
+			for (j, value) in row.iter_mut().enumerate() {
+				let mut res = L::zero();
 				for k in 0..N {
-					res = res + (self.0[i][k] * rhs.0[k][j]);
+					res += self.0[i][k] * rhs.0[k][j];
 				}
-				data[i][j] = res;
+				*value = res;
 			}
 		}
 		Matrix::new(data)
 	}
 }
 
-impl<const T: usize, const N: usize, L: Copy + Debug + ValueFrom<isize>> MulAssign<Matrix<N, N, L>> for Matrix<T, N, L> where L: Add<Output=L> + AddAssign + Mul<Output=L> + ValueFrom<isize> {
+impl<const T: usize, const N: usize, L: Copy + Debug> Mul<ColVector<N, L>> for Matrix<T, N, L> where L: Zero + AddAssign + Mul<Output=L> {
+	type Output = ColVector<T, L>;
+	
+	/// Multiply a column vector by a matrix on the left
+	///
+	/// The matrix must have as many columns as the vector has values
+	fn mul(self, rhs: ColVector<N, L>) -> Self::Output {
+		(self * Matrix::from(rhs)).into()
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Mul<Matrix<T, N, L>> for RowVector<T, L> where L: Zero + AddAssign + Mul<Output=L> {
+	type Output = RowVector<N, L>;
+	
+	/// Multiply a row vector by a matrix on the right
+	///
+	/// The matrix must have as many rows as the vector has values
+	fn mul(self, rhs: Matrix<T, N, L>) -> Self::Output {
+		(Vector::from(self) * rhs).into()
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> MulAssign<Matrix<N, N, L>> for Matrix<T, N, L> where L: Zero + AddAssign + Mul<Output=L> {
 	fn mul_assign(&mut self, rhs: Matrix<N, N, L>) {
-		let mut data = [[0.value_as().unwrap(); N]; T];
-		for i in 0..N {
-			for j in 0..T {
-				let mut res: L = 0.value_as().unwrap();
+		let mut data = [[L::zero(); N]; T];
+		for (i, row) in data.iter_mut().enumerate() {
+			for (j, value) in row.iter_mut().enumerate() {
+				let mut res = L::zero();
 				for k in 0..N {
-					res = res + self[(i, k)] * rhs[(k, j)];
+					res += self[(i, k)] * rhs[(k, j)];
 				}
-				data[i][j] = res;
+				*value = res;
 			}
 		}
 		self.0 = data;
 	}
 }
 
-impl<const T: usize, const N: usize, L: Copy + Debug> Neg for Matrix<T, N, L> where L: Add<Output=L> + AddAssign + ValueFrom<isize> + Mul<Output=L> {
-	type Output = Self;
+impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
+	/// Return the Kronecker product of two matrices
+	///
+	/// For a `T`×`N` matrix `A` and a `P`×`Q` matrix `B` this is the `TP`×`NQ` block matrix where
+	/// block `(i, j)` is `A[(i, j)] * B`. Stable Rust can't calculate the output size from the
+	/// input sizes, so it has to be given as `R` and `C`, usually through a type annotation. This
+	/// will fail to compile if `R≠TP` or `C≠NQ`.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2], [3, 4]]);
+	/// let b = Matrix::new([[0, 1], [1, 0]]);
+	/// let product: Matrix<4, 4, _> = a.kronecker(&b);
+	/// assert_eq!(product, Matrix::new([[0, 1, 0, 2], [1, 0, 2, 0], [0, 3, 0, 4], [3, 0, 4, 0]]))
+	/// ```
+	pub fn kronecker<const P: usize, const Q: usize, const R: usize, const C: usize>(&self, other: &Matrix<P, Q, L>) -> Matrix<R, C, L> where L: Zero + Mul<Output=L> {
+		const { assert!(R == T * P && C == N * Q, "The Kronecker product of a T×N and a P×Q matrix is TP×NQ") }
+		let mut out = Matrix::empty();
+		for i in 0..R {
+			for j in 0..C {
+				out[(i, j)] = self[(i / P, j / Q)] * other[(i % P, j % Q)]
+			}
+		}
+		out
+	}
 	
-	fn neg(self) -> Self::Output {
-		let mut data = self.0.clone();
+	/// Return the Hadamard product of two matrices
+	///
+	/// This multiplies each value in the first matrix by the value in the same place in the
+	/// second matrix
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2], [3, 4]]);
+	/// assert_eq!(a.hadamard(&Matrix::new([[5, 6], [7, 8]])), Matrix::new([[5, 12], [21, 32]]))
+	/// ```
+	pub fn hadamard(&self, other: &Self) -> Self where L: Mul<Output=L> {
+		let mut out = *self;
+		for (value, other) in out.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+			*value = *value * *other
+		}
+		out
+	}
+	
+	/// Return the element-wise division of two matrices
+	///
+	/// This divides each value in the first matrix by the value in the same place in the second
+	/// matrix, and is the inverse of the [Hadamard product][Matrix::hadamard]
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[5., 12.], [21., 32.]]);
+	/// assert_eq!(a.hadamard_div(&Matrix::new([[5., 6.], [7., 8.]])), Matrix::new([[1., 2.], [3., 4.]]))
+	/// ```
+	#[doc(alias="element-wise division")]
+	pub fn hadamard_div(&self, other: &Self) -> Self where L: Div<Output=L> {
+		let mut out = *self;
+		for (value, other) in out.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+			*value = *value / *other
+		}
+		out
+	}
+	
+	/// Return the direct sum of two matrices
+	///
+	/// For a `T`×`N` matrix `A` and a `P`×`Q` matrix `B` this is the `(T+P)`×`(N+Q)` block
+	/// diagonal matrix with `A` in the top left and `B` in the bottom right. Like
+	/// [`kronecker`][Matrix::kronecker], the output size has to be given as `R` and `C` and this will
+	/// fail to compile if `R≠T+P` or `C≠N+Q`.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2]]);
+	/// let b = Matrix::new([[3], [4]]);
+	/// let sum: Matrix<3, 3, _> = a.direct_sum(&b);
+	/// assert_eq!(sum, Matrix::new([[1, 2, 0], [0, 0, 3], [0, 0, 4]]))
+	/// ```
+	pub fn direct_sum<const P: usize, const Q: usize, const R: usize, const C: usize>(&self, other: &Matrix<P, Q, L>) -> Matrix<R, C, L> where L: Zero {
+		const { assert!(R == T + P && C == N + Q, "The direct sum of a T×N and a P×Q matrix is (T+P)×(N+Q)") }
+		let mut out = Matrix::empty();
 		for i in 0..T {
 			for j in 0..N {
-				data[i][j] = data[i][j] * (-1).value_as::<L>().unwrap();
+				out[(i, j)] = self[(i, j)]
 			}
 		}
-		Matrix::new(data)
+		for i in 0..P {
+			for j in 0..Q {
+				out[(T + i, N + j)] = other[(i, j)]
+			}
+		}
+		out
+	}
+}
+
+impl<const T: usize, const N: usize, L: Copy + Debug> Neg for Matrix<T, N, L> where L: Neg<Output=L> {
+	type Output = Self;
+	
+	fn neg(self) -> Self::Output {
+		Matrix::new(self.0.map(|row| row.map(|v| -v)))
 	}
 }
 
@@ -116,53 +228,119 @@ impl<const T: usize, const N: usize, L: Copy + Debug> IndexMut<(usize, usize)> f
 	}
 }
 
+impl<const T: usize, L: Copy + Debug> Add for RowVector<T, L> where L: Add + AddAssign {
+	type Output = Self;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		Self((Vector::new([self.0]) + Vector::new([rhs.0])).0[0])
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> Sub for RowVector<T, L> where L: Sub + SubAssign {
+	type Output = Self;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self((Vector::new([self.0]) - Vector::new([rhs.0])).0[0])
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> Add for ColVector<T, L> where L: Add + AddAssign {
+	type Output = Self;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		(RowVector(self.0) + RowVector(rhs.0)).transpose()
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> Sub for ColVector<T, L> where L: Sub + SubAssign {
+	type Output = Self;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		(RowVector(self.0) - RowVector(rhs.0)).transpose()
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> Index<usize> for RowVector<T, L> {
+	type Output = L;
+	
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.0[index]
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> IndexMut<usize> for RowVector<T, L> {
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		&mut self.0[index]
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> Index<usize> for ColVector<T, L> {
+	type Output = L;
+	
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.0[index]
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> IndexMut<usize> for ColVector<T, L> {
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		&mut self.0[index]
+	}
+}
+
 impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
 	/// Generate a new matrix from a given array `[[L; N]; T]`
 	pub fn new(data: [[L; N]; T]) -> Self {
 		Self(data)
 	}
 	
-	pub fn conj<Q: Copy + Debug>(&self) -> Matrix<T, N, Complex<Q>> where L: ValueFrom<isize> + ValueInto<Complex<Q>>, Q: Neg<Output=Q> + ValueFrom<isize> {
-		let mut data = [[Complex::from_real(0.value_as().unwrap()); N]; T];
-		for n in 0..T {
-			for m in 0..N {
-				data[n][m] = self.0[n][m].value_into().unwrap().conj()
-			}
-		}
-		Matrix::new(data)
+	/// Return the complex conjugate of the matrix
+	///
+	/// Each value is converted into a [`Complex`] value and then conjugated. Real matrices will
+	/// therefore give a matrix of complex values with no imaginary part
+	pub fn conj<Q>(&self) -> Matrix<T, N, Complex<Q>> where L: ValueInto<Complex<Q>>, Q: Copy + Debug + Neg<Output=Q> {
+		Matrix::new(self.0.map(|row| row.map(|v| v.value_into().unwrap().conj())))
 	}
 }
 
 impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
 	/// Transform the type of the data to the specified type assuming given traits are met
 	///
-	/// The new data type (dtype) must implement `Copy`, `Debug`, `ValueFrom<L>`, and
-	/// `ValueFrom<isize>` where `L` is the current dtype. If you're using any built-in numerical value (`i8` to `i64` and `isize`
+	/// The new data type (dtype) must implement `Copy`, `Debug`, and `ValueFrom<L>` where `L` is the
+	/// current dtype. If you're using any built-in numerical value (`i8` to `i64` and `isize`
 	/// and unsigned equivalents as well as `f32`, `f64` satisfy these traits). If you're using a
 	/// custom dtype you need to implement these manually.
 	///
+	/// This will panic if any value can't be represented in the new dtype. Use
+	/// [`try_dtype`][Matrix::try_dtype] to handle this instead.
+	///
 	/// > The `ValueFrom` trait is defined in the [conv](https://crates.io/crates/conv) crate
-	pub fn dtype<Q>(self) -> Matrix<T, N, Q> where Q: Copy + Debug + ValueFrom<L> + ValueFrom<isize> {
-		let mut out= [[0.value_as().unwrap(); N]; T];
-		for i in 0..T {
-			for n in 0..N {
-				out[i][n] = self[(i, n)].value_as::<Q>().unwrap()
-			}
+	pub fn dtype<Q>(self) -> Matrix<T, N, Q> where Q: Copy + Debug + ValueFrom<L> {
+		self.try_dtype().expect("Values could not be converted to the new dtype")
+	}
+	
+	/// Transform the type of the data to the specified type
+	///
+	/// This is the same as [`dtype`][Matrix::dtype] but returns [`Error::ConversionOverflow`] if
+	/// any value can't be represented in the new dtype
+	/// ```
+	/// # use lineas::{Error, Matrix};
+	/// assert_eq!(Matrix::new([[1, 200]]).try_dtype::<u8>(), Ok(Matrix::new([[1, 200]])));
+	/// assert_eq!(Matrix::new([[1, 300]]).try_dtype::<u8>(), Err(Error::ConversionOverflow))
+	/// ```
+	pub fn try_dtype<Q>(self) -> Result<Matrix<T, N, Q>, Error> where Q: Copy + Debug + ValueFrom<L> {
+		let values = self.0.map(|row| row.map(|v| v.value_as::<Q>().ok()));
+		if values.iter().flatten().any(Option::is_none) {
+			return Err(Error::ConversionOverflow)
 		}
-		Matrix::new(out)
+		Ok(Matrix::new(values.map(|row| row.map(Option::unwrap))))
 	}
 	
 	/// Transpose the matrix
 	///
 	/// Returns the transposed matrix, leaving the original unchanged
-	pub fn transpose(&self) -> Matrix<N, T, L> where L: ValueFrom<isize> {
-		let mut data = [[0.value_as().unwrap(); T]; N];
-		for i in 0..T {
-			for j in 0..N {
-				data[j][i] = self.0[i][j];
-			}
-		}
-		Matrix::new(data)
+	pub fn transpose(&self) -> Matrix<N, T, L> {
+		Matrix::new(std::array::from_fn(|j| std::array::from_fn(|i| self.0[i][j])))
 	}
 	
 	/// Scale the matrix
@@ -175,12 +353,10 @@ impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
 	/// assert_eq!(lhs.scale(2), rhs)
 	/// ```
 	pub fn scale<V>(&self, v: V) -> Matrix<T, N, L> where L: Clone + Mul<Output=L> + ValueFrom<V> {
-		let mut data = self.0.clone();
+		let mut data = self.0;
 		let multiplier: L = v.value_as().unwrap();
-		for i in 0..T {
-			for j in 0..N {
-				data[i][j] = data[i][j] * multiplier;
-			}
+		for value in data.iter_mut().flatten() {
+			*value = *value * multiplier;
 		}
 		Matrix::new(data)
 	}
@@ -205,42 +381,282 @@ impl<const T: usize, const N: usize, L: Copy + Debug> Matrix<T, N, L> {
 	}
 }
 
+/// Calculate the determinant of a square matrix, picking the algorithm from its size and dtype
+pub(crate) fn determinant<L: Scalar, M: Dense<L>>(matrix: &M) -> L {
+	if matrix.shape().0 <= 3 {
+		leibniz_determinant(matrix)
+	} else if L::FIELD {
+		gaussian_determinant(matrix)
+	} else {
+		bareiss_determinant(matrix)
+	}
+}
+
+/// Leibniz's determinant formula based on set permutations. This takes `O(T!)` operations so is
+/// only used for small matrices
+fn leibniz_determinant<L: Scalar, M: Dense<L>>(matrix: &M) -> L {
+	fn is_even(p: &[&usize]) -> bool {
+		let mut out = 0;
+		for i in 0..p.len() {
+			for n in i+1..p.len() {
+				if p[i] > p[n] { out += 1 }
+			}
+		}
+		out % 2 == 0
+	}
+	let size = matrix.shape().0;
+	let mut out = L::zero();
+	let permutation = (0..size).collect::<Vec<usize>>();
+	for p in permutation.iter().permutations(size) {
+		let mut temp = L::one();
+		for (r, c) in p.iter().enumerate() {
+			temp *= matrix[(r, **c)];
+		}
+		if is_even(&p) { out += temp } else { out -= temp }
+	}
+	out
+}
+
+/// Gaussian elimination with partial pivoting. The largest value in each column is used as the
+/// pivot and the determinant is the signed product of the pivots
+fn gaussian_determinant<L: Scalar, M: Dense<L>>(matrix: &M) -> L {
+	let zero = L::zero();
+	let size = matrix.shape().0;
+	let mut data = matrix.clone();
+	let mut out = L::one();
+	for c in 0..size {
+		let pivot = (c..size).max_by(|a, b| data[(*a, c)].modulus().total_cmp(&data[(*b, c)].modulus())).unwrap();
+		if data[(pivot, c)] == zero {
+			return zero
+		}
+		if pivot != c {
+			data.swap_rows(pivot, c);
+			out = -out;
+		}
+		out *= data[(c, c)];
+		for r in c + 1..size {
+			let scale = data[(r, c)] / data[(c, c)];
+			for k in c + 1..size {
+				let value = scale * data[(c, k)];
+				data[(r, k)] -= value
+			}
+		}
+	}
+	out
+}
+
+/// Fraction-free Bareiss elimination. Every division is exact so integer dtypes give an exact
+/// result. Rows are only swapped when a pivot is zero
+fn bareiss_determinant<L: Scalar, M: Dense<L>>(matrix: &M) -> L {
+	let zero = L::zero();
+	let size = matrix.shape().0;
+	let mut data = matrix.clone();
+	let mut previous = L::one();
+	let mut sign = L::one();
+	for k in 0..size - 1 {
+		if data[(k, k)] == zero {
+			match (k + 1..size).find(|r| data[(*r, k)] != zero) {
+				Some(r) => {
+					data.swap_rows(r, k);
+					sign = -sign;
+				}
+				None => return zero
+			}
+		}
+		for i in k + 1..size {
+			for j in k + 1..size {
+				data[(i, j)] = (data[(i, j)] * data[(k, k)] - data[(i, k)] * data[(k, j)]) / previous
+			}
+		}
+		previous = data[(k, k)];
+	}
+	sign * data[(size - 1, size - 1)]
+}
+
 impl<const T: usize, L: Copy + Debug> Matrix<T, T, L> {
 	/// Returns the determinant of a square matrix
 	///
-	/// Calculated using Leibniz's determinant formula based on set permutations. Used instead of a
-	/// recursive formula because recursion is slow
-	pub fn determinant(&self) -> L where L: Add<Output=L> + Mul<Output=L> + ValueFrom<isize> {
-		fn perm_sign(p: Vec<&usize>) -> isize {
-			let mut out = 0;
-			for i in 0..p.len() {
-				for n in i+1..p.len() {
-					if p[i] > p[n] { out += 1 }
+	/// For dtypes with exact division (floats and complex floats) this is calculated with Gaussian
+	/// elimination using partial pivoting. For integer dtypes the fraction-free Bareiss algorithm
+	/// is used instead so the result stays exact. Both take `O(T³)` operations.
+	///
+	/// Matrices of size 3 or smaller use Leibniz's formula directly.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]);
+	/// assert_eq!(a.determinant(), 49)
+	/// ```
+	pub fn determinant(&self) -> L where L: Scalar {
+		determinant(self)
+	}
+	
+	/// Returns the inverse of a square matrix, or `None` if the matrix is singular
+	///
+	/// For dtypes with exact division this uses the [PLU factorisation][PLUFactorisation] followed by
+	/// forward and back substitution. The matrix is treated as singular if any pivot is no larger
+	/// than `T * ε` times the largest value in the matrix, where `ε` is the
+	/// [machine epsilon][Scalar::EPSILON] of the dtype. To change this, use
+	/// [`try_inverse_with_tolerance`][Matrix::try_inverse_with_tolerance].
+	///
+	/// This returns [`Error::SingularMatrix`] if the matrix is singular. For integer dtypes the
+	/// adjugate and determinant are calculated exactly using fraction-free Gauss-Jordan
+	/// elimination, and [`Error::InexactDivision`] is returned if the inverse has non-integer values.
+	/// ```
+	/// # use lineas::{Error, Matrix};
+	/// let a = Matrix::new([[2, 1], [5, 3]]);
+	/// assert_eq!(a.try_inverse(), Ok(Matrix::new([[3, -1], [-5, 2]])));
+	/// assert_eq!(Matrix::new([[1, 2], [2, 4]]).try_inverse(), Err(Error::SingularMatrix));
+	/// assert_eq!(Matrix::new([[2, 0], [0, 1]]).try_inverse(), Err(Error::InexactDivision))
+	/// ```
+	pub fn try_inverse(&self) -> Result<Self, Error> where L: Scalar {
+		self.try_inverse_with_tolerance(T as f64 * L::EPSILON)
+	}
+	
+	/// Returns the inverse of a square matrix using a given pivot tolerance
+	///
+	/// This is the same as [`try_inverse`][Matrix::try_inverse] but the matrix is treated as
+	/// singular if any pivot is no larger than `tolerance` times the largest value in the matrix.
+	/// The tolerance is ignored for integer dtypes which are always exact.
+	pub fn try_inverse_with_tolerance(&self, tolerance: f64) -> Result<Self, Error> where L: Scalar {
+		if L::FIELD {
+			Ok(PLUFactorisation::with_tolerance(self, tolerance)?.inverse())
+		} else {
+			self.bareiss_solve(&Self::identity())
+		}
+	}
+	
+	/// Returns the inverse of a square matrix
+	///
+	/// This will panic if the matrix is singular. See [`try_inverse`][Matrix::try_inverse] for a
+	/// non-panicking version.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[4, 0], [2, 2]]).dtype::<f64>();
+	/// assert_eq!(a.inverse(), Matrix::new([[0.25, 0.], [-0.25, 0.5]]))
+	/// ```
+	pub fn inverse(&self) -> Self where L: Scalar {
+		self.try_inverse().expect("Cannot invert a singular matrix")
+	}
+	
+	/// Fraction-free Gauss-Jordan elimination on `[A | B]`. This leaves `[dI | dX]` where `d` is
+	/// the determinant up to sign, so every value stays exact until the final division
+	///
+	/// Returns [`Error::SingularMatrix`] if `A` is singular and [`Error::InexactDivision`] if `X`
+	/// has non-integer values
+	pub(crate) fn bareiss_solve<const K: usize>(&self, b: &Matrix<T, K, L>) -> Result<Matrix<T, K, L>, Error> where L: Scalar {
+		let zero = L::zero();
+		let mut left = self.0;
+		let mut right = b.0;
+		let mut previous = L::one();
+		for k in 0..T {
+			if left[k][k] == zero {
+				let r = (k + 1..T).find(|r| left[*r][k] != zero).ok_or(Error::SingularMatrix)?;
+				left.swap(r, k);
+				right.swap(r, k);
+			}
+			for i in (0..T).filter(|i| *i != k) {
+				for j in 0..T {
+					if j != k {
+						left[i][j] = (left[k][k] * left[i][j] - left[i][k] * left[k][j]) / previous
+					}
 				}
+				let pivot_row = right[k];
+				for (value, pivot) in right[i].iter_mut().zip(pivot_row) {
+					*value = (left[k][k] * *value - left[i][k] * pivot) / previous
+				}
+				left[i][k] = zero;
 			}
-			if out % 2 == 0 { 1 } else { -1 }
+			previous = left[k][k];
 		}
-		let mut out = 0.value_as().unwrap();
-		let permutation = (0..T).collect::<Vec<usize>>();
-		for p in permutation.iter().permutations(T) {
-			let mut temp: L = 1.value_as().unwrap();
-			for (r, c) in p.iter().enumerate() {
-				temp = temp * self[(r, **c)];
+		let mut out = Matrix::new(right);
+		for value in out.0.iter_mut().flatten() {
+			let quotient = *value / previous;
+			if quotient * previous != *value {
+				return Err(Error::InexactDivision)
 			}
-			out = out + perm_sign(p).value_as::<L>().unwrap() * temp;
+			*value = quotient
 		}
-		out
+		Ok(out)
+	}
+	
+	/// Return the trace of the matrix
+	///
+	/// This is the sum of the values along the diagonal
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, 2], [3, 4]]).trace(), 5)
+	/// ```
+	pub fn trace(&self) -> L where L: Zero {
+		(0..T).fold(L::zero(), |a, i| a + self[(i, i)])
+	}
+	
+	/// Return the characteristic polynomial of the matrix
+	///
+	/// This is the polynomial `det(xI - A)`, so its roots are the eigenvalues of the matrix. It is
+	/// calculated with Berkowitz's algorithm in `O(T⁴)` operations without any symbolic
+	/// determinants or divisions. It works for any [`Ring`] dtype with commutative multiplication,
+	/// which is every dtype in this crate, and is exact for integer, [`Rational`][crate::Rational]
+	/// and [`ModP`][crate::ModP] dtypes.
+	/// ```
+	/// # use lineas::Matrix;
+	/// use lineas::polynomials::Polynomial;
+	/// let a = Matrix::new([[2, 1], [1, 2]]);
+	/// assert_eq!(a.characteristic_polynomial(), Polynomial::new(vec![1, -4, 3]))
+	/// ```
+	pub fn characteristic_polynomial(&self) -> Polynomial<L> where L: Ring {
+		Polynomial::new(self.berkowitz())
+	}
+	
+	/// Return the adjugate of the matrix
+	///
+	/// This is the transpose of the cofactor matrix, so `A * adj(A) == det(A) * I`. Unlike the
+	/// inverse, the adjugate exists for singular matrices. It is calculated from the
+	/// [characteristic polynomial][Matrix::characteristic_polynomial] without any divisions, so it
+	/// works for the same dtypes and is exact for integer, [`Rational`][crate::Rational] and
+	/// [`ModP`][crate::ModP] dtypes.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2], [3, 4]]);
+	/// assert_eq!(a.adjugate(), Matrix::new([[4, -2], [-3, 1]]))
+	/// ```
+	pub fn adjugate(&self) -> Self where L: Ring {
+		// By the Cayley–Hamilton theorem adj(A) = (-1)ᵀ⁻¹(Aᵀ⁻¹ + c₁Aᵀ⁻² + ... + cₜ₋₁I)
+		let mut out = Self::identity();
+		for coefficient in self.berkowitz().into_iter().take(T).skip(1) {
+			out = *self * out;
+			for i in 0..T {
+				out[(i, i)] += coefficient
+			}
+		}
+		if T & 1 == 0 { -out } else { out }
+	}
+	
+	/// Berkowitz's algorithm. Returns the characteristic polynomial coefficients in decreasing
+	/// order of power
+	///
+	/// The characteristic polynomial of each leading principal submatrix is found from the previous
+	/// one by multiplying by a Toeplitz matrix, so no divisions are needed
+	fn berkowitz(&self) -> Vec<L> where L: Ring {
+		let mut coefficients = vec![L::one()];
+		for r in 0..T {
+			// The first column of the Toeplitz matrix is 1, -aᵣᵣ, -RC, -RAC, -RA²C, ... where A is
+			// the leading r×r submatrix, R the rest of row r, and C the rest of column r
+			let mut column = vec![L::one(), -self[(r, r)]];
+			let mut v = (0..r).map(|i| self[(i, r)]).collect::<Vec<L>>();
+			for _ in 0..r {
+				column.push(-(0..r).fold(L::zero(), |a, j| a + self[(r, j)] * v[j]));
+				v = (0..r).map(|i| (0..r).fold(L::zero(), |a, j| a + self[(i, j)] * v[j])).collect();
+			}
+			coefficients = (0..r + 2).map(|i| (0..=i.min(r)).fold(L::zero(), |a, j| a + column[i - j] * coefficients[j])).collect();
+		}
+		coefficients
 	}
 	
 	/// Return the diagonal of the matrix
 	///
 	/// Returns a `Vector<T, L>` of values alog the diagonal of the matrix
-	pub fn diag(&self) -> Vector<T, L> where L: ValueFrom<isize> {
-		let mut data = [0.value_as().unwrap(); T];
-		for i in 0..T {
-			data[i] = self[(i, i)];
-		}
-		Vector::new([data])
+	pub fn diag(&self) -> Vector<T, L> {
+		Vector::new([std::array::from_fn(|i| self[(i, i)])])
 	}
 	
 	/// Set the current matrix to the transposed version of itself
@@ -257,3 +673,65 @@ impl<const T: usize, L: Copy + Debug> Matrix<T, T, L> {
 		}
 	}
 }
+
+impl<const T: usize> Matrix<T, T, f64> {
+	/// Return the eigenvalues of the matrix
+	///
+	/// Real matrices can have complex eigenvalues, which come in conjugate pairs, so these are
+	/// returned as complex values in ascending order of their real part and then imaginary part.
+	/// Repeated eigenvalues are included once for each time they are repeated.
+	///
+	/// The matrix is reduced to upper Hessenberg form and then the implicitly shifted Francis
+	/// double-shift QR algorithm is used. This returns [`Error::NoConvergence`] if the iteration
+	/// does not converge, which is very rare.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[0, -1], [1, 0]]).dtype::<f64>();
+	/// let values = a.eigenvalues().unwrap();
+	/// assert_eq!(values.len(), 2);
+	/// assert!(values[0].real().abs() < 1e-15 && (values[0].imag() + 1.).abs() < 1e-15);
+	/// assert!(values[1].real().abs() < 1e-15 && (values[1].imag() - 1.).abs() < 1e-15)
+	/// ```
+	pub fn eigenvalues(&self) -> Result<Vec<Complex<f64>>, Error> {
+		let mut values = eigen::real_eigenvalues(self.0).ok_or(Error::NoConvergence)?;
+		values.sort_by(|a, b| a.real.total_cmp(&b.real).then(a.imaginary.total_cmp(&b.imaginary)));
+		Ok(values)
+	}
+	
+	/// Return the eigenvectors of the matrix
+	///
+	/// Returns pairs `(λ, v)` such that `Av = λv`, in the same order as
+	/// [`eigenvalues`][Matrix::eigenvalues]. Each `v` has length 1 and is scaled so its largest
+	/// value is real and positive.
+	///
+	/// Eigenvalues closer together than `1e-6` times the largest value in the matrix are treated
+	/// as repeated, and each repeated eigenvalue is given with a basis for its eigenspace. This
+	/// means defective matrices, which do not have enough linearly independent eigenvectors,
+	/// give fewer than `T` pairs. Like [`eigenvalues`][Matrix::eigenvalues], this returns
+	/// [`Error::NoConvergence`] if the iteration does not converge.
+	/// ```
+	/// # use lineas::{Complex, Matrix};
+	/// let a = Matrix::new([[2, 0], [0, 3]]).dtype::<f64>();
+	/// let pairs = a.eigenvectors().unwrap();
+	/// assert_eq!(pairs[0], (Complex::from_real(2.), Matrix::new([[Complex::from_real(1.), Complex::from_real(0.)]])));
+	/// assert_eq!(pairs[1], (Complex::from_real(3.), Matrix::new([[Complex::from_real(0.), Complex::from_real(1.)]])))
+	/// ```
+	#[allow(clippy::type_complexity)]
+	pub fn eigenvectors(&self) -> Result<Vec<(Complex<f64>, Vector<T, Complex<f64>>)>, Error> {
+		let scale = self.max_abs();
+		let tolerance = 1e-6 * if scale == 0. { 1. } else { scale };
+		let a = self.0.map(|row| row.map(Complex::from_real));
+		let values = eigen::real_eigenvalues(self.0).ok_or(Error::NoConvergence)?;
+		Ok(eigen::eigenspaces(a, values, tolerance).into_iter().flat_map(|(value, _, basis)| {
+			basis.into_iter().map(move |mut vector| {
+				let largest = vector.iter().copied().fold(Complex::from_real(0f64), |a, b| if b.abs() > a.abs() { b } else { a });
+				let length = vector.iter().map(|v| v.abs().powi(2)).sum::<f64>().sqrt();
+				let factor = largest.conj() / Complex::from_real(largest.abs() * length);
+				for v in vector.iter_mut() {
+					*v *= factor
+				}
+				(value, Vector::new([vector]))
+			})
+		}).collect())
+	}
+}
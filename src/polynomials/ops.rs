@@ -1,7 +1,8 @@
 use std::fmt::{Debug, Error};
-use std::ops::{Add, Sub, AddAssign, SubAssign, Mul, MulAssign, Neg};
+use std::ops::{Add, Sub, AddAssign, SubAssign, Mul, MulAssign, Div, Rem, Neg};
 use conv::{ConvUtil, ValueFrom, ValueInto};
 use crate::polynomials::Polynomial;
+use crate::scalar::{Field, Ring, Zero};
 
 impl<L: Copy + Debug> ValueFrom<L> for Polynomial<L> {
 	type Err = Error;
@@ -24,7 +25,16 @@ impl<L: Copy + Debug> Polynomial<L> {
 	///
 	/// This works the same as [`Matrix::dtype`][crate::Matrix::dtype]
 	pub fn dtype<Q>(&self) -> Polynomial<Q> where Q: ValueFrom<L> + Copy + Debug {
-		Polynomial(self.0.iter().map(|t| (*t).value_as::<Q>().unwrap()).collect())
+		self.try_dtype().expect("Coefficients could not be converted to the new dtype")
+	}
+	
+	/// Change the data type of the polynomial coefficients.
+	///
+	/// Returns [`Error::ConversionOverflow`][crate::Error::ConversionOverflow] if any coefficient
+	/// can't be represented in the new dtype
+	pub fn try_dtype<Q>(&self) -> Result<Polynomial<Q>, crate::Error> where Q: ValueFrom<L> + Copy + Debug {
+		self.0.iter().map(|t| (*t).value_as::<Q>()).collect::<Result<Vec<Q>, _>>()
+			.map(Polynomial).map_err(|_| crate::Error::ConversionOverflow)
 	}
 	
 	/// Minify the polynomial coefficients by removing leading zeroes
@@ -35,10 +45,10 @@ impl<L: Copy + Debug> Polynomial<L> {
 	/// let minified = Polynomial::new(vec![4, 2]);
 	/// assert_eq!(example.minify(), minified)
 	/// ```
-	pub fn minify(&self) -> Self where L: ValueFrom<isize> + PartialEq {
+	pub fn minify(&self) -> Self where L: Zero + PartialEq {
 		let mut out = Vec::new();
 		for i in self.0.iter() {
-			if out.len() == 0 && i == &0.value_as::<L>().unwrap() {
+			if out.is_empty() && i == &L::zero() {
 				continue
 			}
 			out.push(*i)
@@ -46,71 +56,134 @@ impl<L: Copy + Debug> Polynomial<L> {
 		Self(out)
 	}
 	
-	/// Return the degree of the matrix
+	/// Return the degree of the polynomial
 	///
+	/// The zero polynomial, including one with no coefficients, has degree 0
 	/// ```
 	/// # use lineas::polynomials::Polynomial;
-	/// assert_eq!(Polynomial::new(vec![0, 4, 3]).degree(), 1)
+	/// assert_eq!(Polynomial::new(vec![0, 4, 3]).degree(), 1);
+	/// assert_eq!(Polynomial::<i32>::new(Vec::new()).degree(), 0)
 	/// ```
-	pub fn degree(&self) -> usize where L: ValueFrom<isize> + PartialEq {
-		self.minify().0.len() - 1
+	pub fn degree(&self) -> usize where L: Zero + PartialEq {
+		self.minify().0.len().saturating_sub(1)
 	}
 	
 	/// Return the real roots of a polynomial
 	///
-	/// For a 0th, 1st, 2nd, and 3rd degree polynomial this can be exactly calculated, but higher
-	/// order polynomial roots must be calculated numerically and thus will be slightly inaccurate
+	/// The roots of 0th, 1st, and 2nd degree polynomials are calculated exactly. Higher degree
+	/// polynomials return [`Error::UnsupportedDegree`][crate::Error::UnsupportedDegree]
 	///
 	/// ```
 	/// # use lineas::polynomials::Polynomial;
+	/// # use lineas::Error;
 	/// let example = Polynomial::new(vec![1, 1, -6]);
-	/// assert_eq!(example.real_roots(), vec![2f32, -3f32])
+	/// assert_eq!(example.real_roots(), Ok(vec![2f32, -3f32]));
+	/// assert_eq!(Polynomial::new(vec![1, 0, 0, -1]).real_roots(), Err(Error::UnsupportedDegree(3)))
 	/// ```
-	pub fn real_roots(&self) -> Vec<f32> where L: ValueFrom<isize> + PartialEq + Mul<Output=L> + Neg<Output=L> + Add<Output=L> + ValueInto<f32> {
+	pub fn real_roots(&self) -> Result<Vec<f32>, crate::Error> where L: Ring + ValueInto<f32> {
+		let to_f32 = |v: L| -> Result<f32, crate::Error> { v.value_into().map_err(|_| crate::Error::ConversionOverflow) };
 		let minified = self.minify();
-		match minified.degree() + 1 {
+		Ok(match minified.0.len() {
 			0 => vec![0.],
-			1 => if minified.0 == vec![0.value_as().unwrap()] { vec![0.] } else { Vec::new() },
-			2 => {
-				let discriminant = minified.0[1] * minified.0[1] + (-4).value_as::<L>().unwrap() * minified.0[2] * minified.0[0];
-				let discriminant: f32 = discriminant.value_into().unwrap();
+			1 => Vec::new(),
+			2 => vec![-to_f32(minified.0[1])? / to_f32(minified.0[0])?],
+			3 => {
+				let discriminant = to_f32(minified.0[1] * minified.0[1] - L::from_usize(4) * minified.0[2] * minified.0[0])?;
+				let (a, b) = (to_f32(minified.0[0])?, to_f32(minified.0[1])?);
 				if discriminant < 0. {
 					Vec::new()
 				} else if discriminant == 0. {
-					vec![-minified.0[1].value_into().unwrap() / (2f32 * minified.0[2].value_into().unwrap())]
+					vec![-b / (2f32 * a)]
 				} else {
-					vec![
-						(-minified.0[1].value_into().unwrap() + discriminant) / (2f32 * minified.0[2].value_into().unwrap()),
-						-(minified.0[1].value_into().unwrap() + discriminant) / (2f32 * minified.0[2].value_into().unwrap())
-					]
+					vec![(-b + discriminant.sqrt()) / (2f32 * a), -(b + discriminant.sqrt()) / (2f32 * a)]
 				}
 			},
-			_ => panic!("no")
+			n => return Err(crate::Error::UnsupportedDegree(n - 1))
+		})
+	}
+	
+	/// Divide by another polynomial, returning the quotient and remainder
+	///
+	/// This is polynomial long division, so `self = quotient * divisor + remainder` and the
+	/// remainder has a lower degree than the divisor. The coefficients must be a [`Field`] so that
+	/// the division is exact. This will panic if the divisor is zero.
+	/// ```
+	/// # use lineas::polynomials::Polynomial;
+	/// # use lineas::Rational;
+	/// let a = Polynomial::new(vec![2, 3, 1]).dtype::<Rational<i32>>();
+	/// let b = Polynomial::new(vec![2, 0]).dtype::<Rational<i32>>();
+	/// let (quotient, remainder) = a.div_rem(&b);
+	/// assert_eq!(quotient, Polynomial::new(vec![Rational::new(1, 1), Rational::new(3, 2)]));
+	/// assert_eq!(remainder, Polynomial::new(vec![Rational::new(1, 1)]))
+	/// ```
+	pub fn div_rem(&self, divisor: &Self) -> (Self, Self) where L: Field {
+		let divisor = divisor.minify();
+		assert!(!divisor.0.is_empty(), "Cannot divide by the zero polynomial");
+		let mut remainder = self.minify().0;
+		if remainder.len() < divisor.0.len() {
+			return (Polynomial(Vec::new()), Polynomial(remainder))
+		}
+		let lead = divisor.0[0].inverse();
+		let steps = remainder.len() - divisor.0.len() + 1;
+		let mut quotient = Vec::with_capacity(steps);
+		for i in 0..steps {
+			let factor = remainder[i] * lead;
+			for (j, value) in divisor.0.iter().enumerate() {
+				remainder[i + j] -= factor * *value
+			}
+			quotient.push(factor)
+		}
+		(Polynomial(quotient), Polynomial(remainder.split_off(steps)).minify())
+	}
+	
+	/// Return the greatest common divisor of two polynomials
+	///
+	/// This is the highest degree polynomial that divides both, found with the Euclidean
+	/// algorithm. It's scaled so its leading coefficient is one, and the GCD of two zero
+	/// polynomials is the zero polynomial. The coefficients must be a [`Field`].
+	/// ```
+	/// # use lineas::polynomials::Polynomial;
+	/// # use lineas::ModP;
+	/// let a = Polynomial::new(vec![1, 0, -1]).dtype::<ModP<5>>();
+	/// let b = Polynomial::new(vec![2, 4, 2]).dtype::<ModP<5>>();
+	/// assert_eq!(a.gcd(&b), Polynomial::new(vec![1, 1]).dtype())
+	/// ```
+	pub fn gcd(&self, other: &Self) -> Self where L: Field {
+		let (mut a, mut b) = (self.minify(), other.minify());
+		while !b.0.is_empty() {
+			let remainder = a.div_rem(&b).1;
+			(a, b) = (b, remainder)
+		}
+		match a.0.first() {
+			Some(lead) => {
+				let scale = lead.inverse();
+				Polynomial(a.0.iter().map(|v| *v * scale).collect())
+			},
+			None => a
 		}
 	}
 }
 
-impl<L: Copy + Debug, Q> Add<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Add<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> Add<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Add<Output=L> + PartialEq {
 	type Output = Polynomial<L>;
 	
 	fn add(self, rhs: Q) -> Self::Output {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
 		let len = rhs.0.len().max(self.0.len());
 		let (rhs, lhs) = if rhs.0.len() < len {
-			let mut rhs_new = vec![0.value_as::<L>().unwrap(); len - rhs.0.len()];
+			let mut rhs_new = vec![L::zero(); len - rhs.0.len()];
 			rhs_new.extend(rhs.0);
 			(Polynomial(rhs_new), self)
 		} else if self.0.len() < len {
-			let mut self_new = vec![0.value_as::<L>().unwrap(); len - self.0.len()];
+			let mut self_new = vec![L::zero(); len - self.0.len()];
 			self_new.extend(self.0);
 			(rhs, Polynomial(self_new))
 		} else {
 			(rhs, self)
 		};
 		let mut out = Vec::new();
-		let mut iter = rhs.0.iter().zip(lhs.0.iter());
-		while let Some((l, r)) = iter.next() {
-			if *l + *r == 0.value_as::<L>().unwrap() && out.len() == 0 {
+		for (l, r) in rhs.0.iter().zip(lhs.0.iter()) {
+			if *l + *r == L::zero() && out.is_empty() {
 				continue
 			}
 			out.push(*l + *r);
@@ -119,25 +192,24 @@ impl<L: Copy + Debug, Q> Add<Q> for Polynomial<L> where Q: ValueInto<Polynomial<
 	}
 }
 
-impl<L: Copy + Debug, Q> AddAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Add<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> AddAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Add<Output=L> + PartialEq {
 	fn add_assign(&mut self, rhs: Q) {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
 		let len = rhs.0.len().max(self.0.len());
 		let (rhs, lhs) = if rhs.0.len() < len {
-			let mut rhs_new = vec![0.value_as::<L>().unwrap(); len - rhs.0.len()];
+			let mut rhs_new = vec![L::zero(); len - rhs.0.len()];
 			rhs_new.extend(rhs.0);
 			(Polynomial(rhs_new), self.clone())
 		} else if self.0.len() < len {
-			let mut self_new = vec![0.value_as::<L>().unwrap(); len - self.0.len()];
+			let mut self_new = vec![L::zero(); len - self.0.len()];
 			self_new.extend(self.0.clone());
 			(rhs, Polynomial(self_new))
 		} else {
 			(rhs, self.clone())
 		};
 		let mut out = Vec::new();
-		let mut iter = rhs.0.iter().zip(lhs.0.iter());
-		while let Some((l, r)) = iter.next() {
-			if *l + *r == 0.value_as::<L>().unwrap() && out.len() == 0 {
+		for (l, r) in rhs.0.iter().zip(lhs.0.iter()) {
+			if *l + *r == L::zero() && out.is_empty() {
 				continue
 			}
 			out.push(*l + *r);
@@ -146,27 +218,26 @@ impl<L: Copy + Debug, Q> AddAssign<Q> for Polynomial<L> where Q: ValueInto<Polyn
 	}
 }
 
-impl<L: Copy + Debug, Q> Sub<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Sub<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> Sub<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Sub<Output=L> + PartialEq {
 	type Output = Polynomial<L>;
 	
 	fn sub(self, rhs: Q) -> Self::Output {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
 		let len = rhs.0.len().max(self.0.len());
 		let (rhs, lhs) = if rhs.0.len() < len {
-			let mut rhs_new = vec![0.value_as::<L>().unwrap(); len - rhs.0.len()];
+			let mut rhs_new = vec![L::zero(); len - rhs.0.len()];
 			rhs_new.extend(rhs.0);
 			(Polynomial(rhs_new), self)
 		} else if self.0.len() < len {
-			let mut self_new = vec![0.value_as::<L>().unwrap(); len - self.0.len()];
+			let mut self_new = vec![L::zero(); len - self.0.len()];
 			self_new.extend(self.0);
 			(rhs, Polynomial(self_new))
 		} else {
 			(rhs, self)
 		};
 		let mut out = Vec::new();
-		let mut iter = rhs.0.iter().zip(lhs.0.iter());
-		while let Some((l, r)) = iter.next() {
-			if *l - *r == 0.value_as::<L>().unwrap() && out.len() == 0 {
+		for (l, r) in rhs.0.iter().zip(lhs.0.iter()) {
+			if *l - *r == L::zero() && out.is_empty() {
 				continue
 			}
 			out.push(*l - *r);
@@ -175,25 +246,24 @@ impl<L: Copy + Debug, Q> Sub<Q> for Polynomial<L> where Q: ValueInto<Polynomial<
 	}
 }
 
-impl<L: Copy + Debug, Q> SubAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Sub<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> SubAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Sub<Output=L> + PartialEq {
 	fn sub_assign(&mut self, rhs: Q) {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
 		let len = rhs.0.len().max(self.0.len());
 		let (rhs, lhs) = if rhs.0.len() < len {
-			let mut rhs_new = vec![0.value_as::<L>().unwrap(); len - rhs.0.len()];
+			let mut rhs_new = vec![L::zero(); len - rhs.0.len()];
 			rhs_new.extend(rhs.0);
 			(Polynomial(rhs_new), self.clone())
 		} else if self.0.len() < len {
-			let mut self_new = vec![0.value_as::<L>().unwrap(); len - self.0.len()];
+			let mut self_new = vec![L::zero(); len - self.0.len()];
 			self_new.extend(self.0.clone());
 			(rhs, Polynomial(self_new))
 		} else {
 			(rhs, self.clone())
 		};
 		let mut out = Vec::new();
-		let mut iter = rhs.0.iter().zip(lhs.0.iter());
-		while let Some((l, r)) = iter.next() {
-			if *l - *r == 0.value_as::<L>().unwrap() && out.len() == 0 {
+		for (l, r) in rhs.0.iter().zip(lhs.0.iter()) {
+			if *l - *r == L::zero() && out.is_empty() {
 				continue
 			}
 			out.push(*l - *r);
@@ -202,12 +272,12 @@ impl<L: Copy + Debug, Q> SubAssign<Q> for Polynomial<L> where Q: ValueInto<Polyn
 	}
 }
 
-impl<L: Copy + Debug, Q> Mul<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Add<Output=L> + Mul<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> Mul<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Add<Output=L> + Mul<Output=L> + PartialEq {
 	type Output = Polynomial<L>;
 	
 	fn mul(self, rhs: Q) -> Self::Output {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
-		let mut out = vec![0.value_as::<L>().unwrap(); self.degree() + rhs.degree() + 1];
+		let mut out = vec![L::zero(); (self.0.len() + rhs.0.len()).saturating_sub(1)];
 		for (p1, v1) in self.0.iter().enumerate() {
 			for (p2, v2) in rhs.0.iter().enumerate() {
 				out[p1 + p2] = out[p1 + p2] + *v1 * *v2
@@ -217,10 +287,10 @@ impl<L: Copy + Debug, Q> Mul<Q> for Polynomial<L> where Q: ValueInto<Polynomial<
 	}
 }
 
-impl<L: Copy + Debug, Q> MulAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: ValueFrom<isize> + Add<Output=L> + Mul<Output=L> + PartialEq {
+impl<L: Copy + Debug, Q> MulAssign<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Zero + Add<Output=L> + Mul<Output=L> + PartialEq {
 	fn mul_assign(&mut self, rhs: Q) {
 		let rhs = rhs.value_as::<Polynomial<L>>().unwrap();
-		let mut out = vec![0.value_as::<L>().unwrap(); self.degree() + rhs.degree() + 1];
+		let mut out = vec![L::zero(); (self.0.len() + rhs.0.len()).saturating_sub(1)];
 		for (p1, v1) in self.0.iter().enumerate() {
 			for (p2, v2) in rhs.0.iter().enumerate() {
 				out[p1 + p2] = out[p1 + p2] + *v1 * *v2
@@ -230,7 +300,29 @@ impl<L: Copy + Debug, Q> MulAssign<Q> for Polynomial<L> where Q: ValueInto<Polyn
 	}
 }
 
-impl<L: Copy + Debug> Neg for Polynomial<L> where L: ValueFrom<isize> + Add<Output=L> + PartialEq + Neg<Output=L> {
+impl<L: Copy + Debug, Q> Div<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Field {
+	type Output = Polynomial<L>;
+	
+	/// Return the quotient of polynomial long division
+	///
+	/// See [`div_rem`][Polynomial::div_rem]
+	fn div(self, rhs: Q) -> Self::Output {
+		self.div_rem(&rhs.value_as::<Polynomial<L>>().unwrap()).0
+	}
+}
+
+impl<L: Copy + Debug, Q> Rem<Q> for Polynomial<L> where Q: ValueInto<Polynomial<L>>, L: Field {
+	type Output = Polynomial<L>;
+	
+	/// Return the remainder of polynomial long division
+	///
+	/// See [`div_rem`][Polynomial::div_rem]
+	fn rem(self, rhs: Q) -> Self::Output {
+		self.div_rem(&rhs.value_as::<Polynomial<L>>().unwrap()).1
+	}
+}
+
+impl<L: Copy + Debug> Neg for Polynomial<L> where L: Zero + Add<Output=L> + PartialEq + Neg<Output=L> {
 	type Output = Polynomial<L>;
 	
 	fn neg(self) -> Self::Output {
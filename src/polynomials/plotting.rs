@@ -5,6 +5,7 @@
 use std::fmt::{Debug, Display};
 use conv::{ConvUtil, ValueFrom, ValueInto};
 use itertools::Itertools;
+use crate::scalar::Ring;
 use plotters::coord::types::RangedCoordf32;
 use plotters::prelude::*;
 
@@ -46,7 +47,7 @@ impl<Q> Default for PlotArgs<Q> where Q: Color + Default + Copy {
 	}
 }
 
-impl<L: Copy + Debug + Display> crate::polynomials::Polynomial<L> where L: ValueFrom<isize> + PartialEq + ValueInto<f32>, f32: ValueFrom<L> {
+impl<L: Copy + Debug + Display> crate::polynomials::Polynomial<L> where L: Ring + ValueInto<f32>, f32: ValueFrom<L> {
 	/// Plot a polynomial on a graph
 	///
 	/// This requires that you provide the char to plot on and arguments for plotting the chart.
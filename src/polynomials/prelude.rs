@@ -8,7 +8,7 @@ use std::fmt::Debug;
 /// For example the polynomial 3x²-5x+1 would be represented as
 /// ```
 /// # use lineas::polynomials::Polynomial;
-/// Polynomial::new(vec![3, -5, 1])
+/// Polynomial::new(vec![3, -5, 1]);
 /// ```
 ///
 /// Make sure you include the polynomial struct as
@@ -21,11 +21,66 @@ use std::fmt::Debug;
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Matrix<const T: usize, const N: usize, L: Copy + Debug> (pub(crate) [[L; N]; T] );
 
+/// Heap allocated matrix struct
+///
+/// A `BoxedMatrix<T, N, L>` is a `T`×`N` matrix like a [`Matrix`] but its values are stored on the
+/// heap. A `Matrix` is stored inline, so large matrices like a `Matrix<512, 512, f64>` can overflow
+/// the stack. Constructing and multiplying boxed matrices never builds the whole matrix on the
+/// stack.
+///
+/// A `BoxedMatrix` can be converted to and from a `Matrix` with [`From`].
+/// ```
+/// # use lineas::BoxedMatrix;
+/// let a = BoxedMatrix::<512, 512, f64>::identity();
+/// let b = BoxedMatrix::<512, 512, _>::from_fn(|i, j| (i + j) as f64);
+/// assert_eq!(&a * &b, b)
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxedMatrix<const T: usize, const N: usize, L: Copy + Debug> (pub(crate) Box<[[L; N]; T]>);
+
+/// Runtime sized matrix struct
+///
+/// A `DMatrix<L>` stores its values on the heap in row-major order, so its size is chosen at
+/// runtime rather than being part of its type. This is useful for matrices whose size isn't known
+/// at compile time or that are too large to put on the stack.
+///
+/// Operations that need matching sizes, like addition, panic if the sizes don't match. A
+/// [`Matrix`] can be converted into a `DMatrix` with [`From`], and the other way with [`TryFrom`]
+/// which checks the size.
+/// ```
+/// # use lineas::{DMatrix, Matrix};
+/// let a = DMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]);
+/// assert_eq!(a.shape(), (2, 2));
+/// assert_eq!(Matrix::try_from(a), Ok(Matrix::new([[1, 2], [3, 4]])))
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DMatrix<L: Copy + Debug> {
+	pub(crate) rows: usize,
+	pub(crate) cols: usize,
+	pub(crate) data: Vec<L>
+}
+
 /// Vector struct
 ///
 /// Derived from the [matrix][Matrix] and is technically a 1×`T` matrix
 pub type Vector<const T: usize, L> = Matrix<1, T, L>;
 
+/// Row vector struct
+///
+/// A 1×`T` vector made with [`RowVector::new([..])`][RowVector::new]. Unlike a [`Vector`] this
+/// is a separate type to [`Matrix`] so a row vector can only be multiplied by a matrix on its
+/// right. It can be converted to and from a `Vector` or [`ColVector`] with [`From`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RowVector<const T: usize, L: Copy + Debug> (pub(crate) [L; T]);
+
+/// Column vector struct
+///
+/// A `T`×1 vector made with [`ColVector::new([..])`][ColVector::new]. A column vector can only be
+/// multiplied by a matrix on its left. It can be converted to and from a `Matrix<T, 1, L>` or
+/// [`RowVector`] with [`From`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColVector<const T: usize, L: Copy + Debug> (pub(crate) [L; T]);
+
 /// Complex number struct
 ///
 /// This contains two values, a real and an imaginary. It does complex number things as you would
@@ -37,3 +92,45 @@ pub struct Complex<L: Copy + Debug> {
 	pub(crate) real: L,
 	pub(crate) imaginary: L
 }
+
+/// Rational number struct
+///
+/// An exact fraction of two [primitive integers][crate::scalar::Integer], made with
+/// [`Rational::new`]. The fraction is always kept in its lowest terms with a positive denominator,
+/// so equal values have the same numerator and denominator. Arithmetic is exact, so elimination
+/// based algorithms like the [determinant][Matrix::determinant], [inverse][Matrix::inverse], and
+/// [reduced row echelon form][Matrix::rref] give exact results where integers would truncate.
+/// ```
+/// # use lineas::{Matrix, Rational};
+/// let a = Matrix::new([[2, 1], [1, 3]]).dtype::<Rational<i64>>();
+/// let fifth = |n| Rational::new(n, 5);
+/// assert_eq!(a.inverse(), Matrix::new([[fifth(3), fifth(-1)], [fifth(-1), fifth(2)]]))
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rational<I: Copy + Debug> {
+	pub(crate) numerator: I,
+	pub(crate) denominator: I
+}
+
+/// Prime field struct
+///
+/// An integer modulo the prime `P`, made with [`ModP::new`]. Every non-zero value has an inverse,
+/// so matrices and polynomials over `ModP<P>` support exact division, for example to calculate a
+/// [determinant][Matrix::determinant], [inverse][Matrix::inverse], or [rank][Matrix::rank] over the
+/// finite field GF(P). `P` must be prime, which is checked at compile time.
+///
+/// A finite field has no notion of size, so the norms, angles and decompositions that need them
+/// or square roots, such as QR and Cholesky, are not available. [`LU`][crate::decompose::LUDecompose]
+/// and [`LDLᵀ`][crate::decompose::LDLDecompose] decompositions are exact.
+/// ```
+/// # use lineas::{Matrix, ModP};
+/// let a = Matrix::new([[3, 1], [4, 2]]).dtype::<ModP<7>>();
+/// assert_eq!(a.determinant(), ModP::new(2));
+/// assert_eq!(a * a.inverse(), Matrix::identity())
+/// ```
+/// ```compile_fail
+/// # use lineas::{ModP, Vector};
+/// let _ = Vector::new([[3, 4]]).dtype::<ModP<7>>().norm();
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ModP<const P: u64> (pub(crate) u64);
@@ -0,0 +1,249 @@
+//! Implementations for the exact [`Rational`] dtype
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use conv::{NoError, ValueFrom};
+use crate::Rational;
+use crate::scalar::{Integer, Zero};
+
+/// Greatest common divisor of two integers, which is always non-negative
+fn gcd<I: Integer>(a: I, b: I) -> I {
+	let (mut a, mut b) = (abs(a), abs(b));
+	while b != I::zero() {
+		(a, b) = (b, a % b)
+	}
+	a
+}
+
+fn abs<I: Integer>(value: I) -> I {
+	if value < I::zero() { -value } else { value }
+}
+
+/// Floor division of `a` by a positive `b`, returning the quotient and the non-negative remainder
+fn div_floor<I: Integer>(a: I, b: I) -> (I, I) {
+	let (quotient, remainder) = (a / b, a % b);
+	if remainder < I::zero() {
+		(quotient - I::one(), remainder + b)
+	} else {
+		(quotient, remainder)
+	}
+}
+
+impl<I: Integer> Rational<I> {
+	/// Make a new rational value `numerator / denominator`
+	///
+	/// The fraction is reduced to its lowest terms with a positive denominator. This will panic if
+	/// the denominator is zero.
+	/// ```
+	/// # use lineas::Rational;
+	/// let a = Rational::new(4, -6);
+	/// assert_eq!((a.numerator(), a.denominator()), (-2, 3))
+	/// ```
+	pub fn new(numerator: I, denominator: I) -> Self {
+		assert!(denominator != I::zero(), "The denominator of a rational can't be zero");
+		let divisor = gcd(numerator, denominator);
+		let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+		if denominator < I::zero() {
+			Self { numerator: -numerator, denominator: -denominator }
+		} else {
+			Self { numerator, denominator }
+		}
+	}
+	
+	/// Make a new rational value from an integer
+	pub fn from_integer(value: I) -> Self {
+		Self { numerator: value, denominator: I::one() }
+	}
+	
+	/// Return the numerator, which has the same sign as the value
+	pub fn numerator(&self) -> I {
+		self.numerator
+	}
+	
+	/// Return the denominator, which is always positive
+	pub fn denominator(&self) -> I {
+		self.denominator
+	}
+	
+	/// Return `true` if the value is a whole number
+	pub fn is_integer(&self) -> bool {
+		self.denominator == I::one()
+	}
+	
+	/// Return the reciprocal `1/x`
+	///
+	/// This will panic if the value is zero
+	pub fn recip(&self) -> Self {
+		assert!(self.numerator != I::zero(), "Cannot take the reciprocal of zero");
+		Self::new(self.denominator, self.numerator)
+	}
+}
+
+impl<I: Integer> Add for Rational<I> {
+	type Output = Self;
+	
+	fn add(self, rhs: Self) -> Self::Output {
+		let divisor = gcd(self.denominator, rhs.denominator);
+		Self::new(
+			self.numerator * (rhs.denominator / divisor) + rhs.numerator * (self.denominator / divisor),
+			self.denominator / divisor * rhs.denominator
+		)
+	}
+}
+
+impl<I: Integer> AddAssign for Rational<I> {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs
+	}
+}
+
+impl<I: Integer> Sub for Rational<I> {
+	type Output = Self;
+	
+	fn sub(self, rhs: Self) -> Self::Output {
+		self + -rhs
+	}
+}
+
+impl<I: Integer> SubAssign for Rational<I> {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs
+	}
+}
+
+impl<I: Integer> Neg for Rational<I> {
+	type Output = Self;
+	
+	fn neg(self) -> Self::Output {
+		Self { numerator: -self.numerator, denominator: self.denominator }
+	}
+}
+
+impl<I: Integer> Mul for Rational<I> {
+	type Output = Self;
+	
+	/// Multiply two rational values
+	///
+	/// Common factors are cancelled before multiplying to keep the intermediate values small
+	fn mul(self, rhs: Self) -> Self::Output {
+		if self.numerator == I::zero() || rhs.numerator == I::zero() {
+			return Self::zero()
+		}
+		let first = gcd(self.numerator, rhs.denominator);
+		let second = gcd(rhs.numerator, self.denominator);
+		Self::new(
+			(self.numerator / first) * (rhs.numerator / second),
+			(self.denominator / second) * (rhs.denominator / first)
+		)
+	}
+}
+
+impl<I: Integer> MulAssign for Rational<I> {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs
+	}
+}
+
+impl<I: Integer> Div for Rational<I> {
+	type Output = Self;
+	
+	/// Divide two rational values
+	///
+	/// This will panic if `rhs` is zero
+	fn div(self, rhs: Self) -> Self::Output {
+		Mul::mul(self, rhs.recip())
+	}
+}
+
+impl<I: Integer> DivAssign for Rational<I> {
+	fn div_assign(&mut self, rhs: Self) {
+		*self = *self / rhs
+	}
+}
+
+impl<I: Integer> PartialOrd for Rational<I> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<I: Integer> Ord for Rational<I> {
+	/// Compare two values by their continued fractions
+	///
+	/// Cross-multiplying could overflow, so this compares the whole parts and then the reciprocals
+	/// of the remainders in turn, which never leaves the range of the integer type
+	fn cmp(&self, other: &Self) -> Ordering {
+		let zero = I::zero();
+		let (mut a, mut b) = (self.numerator, self.denominator);
+		let (mut c, mut d) = (other.numerator, other.denominator);
+		loop {
+			let ((q, r), (s, t)) = (div_floor(a, b), div_floor(c, d));
+			match (q.cmp(&s), r == zero, t == zero) {
+				(Ordering::Equal, true, true) => return Ordering::Equal,
+				(Ordering::Equal, true, false) => return Ordering::Less,
+				(Ordering::Equal, false, true) => return Ordering::Greater,
+				(Ordering::Equal, false, false) => (a, b, c, d) = (d, t, b, r),
+				(ordering, _, _) => return ordering
+			}
+		}
+	}
+}
+
+/// Convert primitive integers into any [`Rational`] whose integer type can hold them
+macro_rules! rational_from {
+	($($t:ty),*) => {$(
+		impl<I: Integer> ValueFrom<$t> for Rational<I> where I: ValueFrom<$t> {
+			type Err = I::Err;
+			
+			fn value_from(src: $t) -> Result<Self, Self::Err> {
+				Ok(Self::from_integer(I::value_from(src)?))
+			}
+		}
+	)*};
+}
+
+rational_from!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<I: Integer> ValueFrom<Rational<I>> for f64 {
+	type Err = NoError;
+	
+	fn value_from(src: Rational<I>) -> Result<Self, Self::Err> {
+		Ok(src.numerator.to_f64() / src.denominator.to_f64())
+	}
+}
+
+impl<I: Integer> ValueFrom<Rational<I>> for f32 {
+	type Err = NoError;
+	
+	fn value_from(src: Rational<I>) -> Result<Self, Self::Err> {
+		Ok((src.numerator.to_f64() / src.denominator.to_f64()) as f32)
+	}
+}
+
+impl<I: Integer> Debug for Rational<I> {
+	/// Format a rational value the same way as [`Display`]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Debug::fmt(&self.numerator, f)?;
+		if self.is_integer() {
+			Ok(())
+		} else {
+			write!(f, "/{:?}", self.denominator)
+		}
+	}
+}
+
+impl<I: Integer + Display> Display for Rational<I> {
+	/// Format a rational value as `numerator/denominator`, or just the numerator if it's a whole
+	/// number
+	///
+	/// Formatting flags, such as `+`, are applied to the numerator
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.numerator, f)?;
+		if self.is_integer() {
+			Ok(())
+		} else {
+			write!(f, "/{}", self.denominator)
+		}
+	}
+}
@@ -0,0 +1,604 @@
+//! # Numerical properties of dtypes
+//!
+//! Algorithms are written against a small family of traits describing what a dtype can do, rather
+//! than against the operator traits directly:
+//!
+//! - [`Zero`] and [`One`] give the additive and multiplicative identities
+//! - [`Ring`] values can be added, subtracted, and multiplied, like integers
+//! - [`Scalar`] values are rings with a division and enough numerical information, such as how
+//!   large a value is, to choose pivots. Division may truncate, like for integers
+//! - [`Field`] scalars have exact division, like floats
+//! - [`RealField`] and [`ComplexField`] are the real and complex floating point fields
+//! - [`Integer`] values are primitive integers that can be used in a [`Rational`]
+//!
+//! These are implemented for all signed primitive numbers and [`Complex`] values made from them,
+//! as well as the exact [`Rational`] and [`ModP`] dtypes. If you're using a custom dtype you need to implement them manually.
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use crate::{Complex, ModP, Rational};
+
+/// Additive identity trait
+pub trait Zero: Sized + Add<Output=Self> {
+	/// Return the additive identity `0`
+	fn zero() -> Self;
+}
+
+/// Multiplicative identity trait
+pub trait One: Sized + Mul<Output=Self> {
+	/// Return the multiplicative identity `1`
+	fn one() -> Self;
+}
+
+/// Ring trait
+///
+/// A ring has an addition with an identity and negation, and a multiplication with an identity.
+/// Multiplication does not need to be commutative. Integers are the typical example.
+pub trait Ring: Copy + Debug + PartialEq + Zero + One + AddAssign + Sub<Output=Self> + SubAssign + MulAssign + Neg<Output=Self> {
+	/// Return the integer `n` in the ring, which is `1 + 1 + ... + 1`
+	///
+	/// The default implementation takes `O(log n)` additions
+	fn from_usize(n: usize) -> Self {
+		let mut out = Self::zero();
+		let mut power = Self::one();
+		let mut n = n;
+		while n > 0 {
+			if n & 1 == 1 {
+				out += power
+			}
+			power += power;
+			n >>= 1
+		}
+		out
+	}
+}
+
+/// Numerical dtype trait
+///
+/// This is a [`Ring`] with a division operator and some numerical properties that the matrix
+/// algorithms need, such as how large a value is when choosing a pivot. Implemented for `i8` to
+/// `i64`, `isize`, `f32`, `f64`, and [`Complex`] values of any of these.
+pub trait Scalar: Ring + Div<Output=Self> {
+	/// `true` if division is exact, such as for floats, and `false` if division truncates, such
+	/// as for integers
+	///
+	/// This should be `true` exactly when the dtype implements [`Field`]
+	const FIELD: bool;
+	
+	/// Machine epsilon of the dtype, or `0` if the dtype is exact
+	///
+	/// This is used to choose default tolerances when deciding if a value is zero
+	const EPSILON: f64;
+	
+	/// `true` if [`modulus`][Scalar::modulus] is the size of the value and [`sqrt`][Scalar::sqrt]
+	/// is defined for every value that needs one, such as the norm of a vector
+	///
+	/// This is `false` for [`ModP`], and norms, angles and the decompositions that need square
+	/// roots will fail to compile for such dtypes
+	const NORMED: bool = true;
+	
+	/// Return the magnitude of the value
+	///
+	/// This is used to compare values against each other, for example when choosing the largest
+	/// pivot in a column
+	fn modulus(&self) -> f64;
+	
+	/// Return the complex conjugate of the value, which does nothing for real dtypes
+	fn conj(&self) -> Self;
+	
+	/// Return the principal square root of the value
+	///
+	/// For integer dtypes this is rounded towards zero
+	fn sqrt(&self) -> Self;
+	
+	/// Convert the value into a complex float
+	///
+	/// Iterative algorithms such as eigenvalue calculation are done with `Complex<f64>` values
+	/// and converted back afterwards
+	fn to_complex(&self) -> Complex<f64>;
+	
+	/// Convert a complex float back into this dtype
+	///
+	/// Returns `None` if the value cannot be represented to within `tolerance`, for example if a
+	/// real dtype is given a value with a non-zero imaginary part
+	fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self>;
+	
+	/// Return the principal square root of the complex value `real + i·imaginary`
+	///
+	/// This is the [square root][Scalar::sqrt] of [`Complex`] values of this dtype. The default
+	/// implementation is built from the dtype's own square root, so it is rounded for integer
+	/// dtypes, while floats use [`Complex::sqrt`]
+	fn complex_sqrt(real: Self, imaginary: Self) -> Complex<Self> {
+		let two = Self::from_usize(2);
+		let r = (real * real + imaginary * imaginary).sqrt();
+		let root = ((r - real) / two).sqrt();
+		Complex {
+			real: ((r + real) / two).sqrt(),
+			imaginary: if imaginary.to_complex().real < 0. { -root } else { root }
+		}
+	}
+	
+	/// Return `self + other`, or `None` if it overflows
+	///
+	/// This is only checked for integer dtypes, where fraction-free elimination uses it
+	fn checked_add(&self, other: &Self) -> Option<Self> {
+		Some(*self + *other)
+	}
+	
+	/// Return `self * other`, or `None` if it overflows
+	///
+	/// This is only checked for integer dtypes, where fraction-free elimination uses it
+	fn checked_mul(&self, other: &Self) -> Option<Self> {
+		Some(*self * *other)
+	}
+	
+	/// Return `self - other`, or `None` if it overflows
+	///
+	/// This is only checked for integer dtypes, where fraction-free elimination uses it
+	fn checked_sub(&self, other: &Self) -> Option<Self> {
+		Some(*self - *other)
+	}
+}
+
+/// Stop a norm or square root based function compiling for a dtype without [`Scalar::NORMED`]
+pub(crate) fn assert_normed<L: Scalar>() {
+	const { assert!(L::NORMED, "This needs a dtype with a norm and square roots, which ModP does not have") }
+}
+
+/// Field trait
+///
+/// A field is a [`Scalar`] with exact division, so every non-zero value has an inverse. Floats,
+/// complex floats, and exact dtypes like rationals are fields.
+pub trait Field: Scalar + DivAssign {
+	/// Return the multiplicative inverse `1/x`
+	fn inverse(&self) -> Self {
+		Self::one() / *self
+	}
+}
+
+/// Real floating point field trait
+///
+/// Implemented for `f32` and `f64`
+pub trait RealField: Field + PartialOrd {
+	/// Return the absolute value
+	fn abs(&self) -> Self;
+	
+	/// Convert the value into an `f64`
+	fn to_f64(&self) -> f64;
+	
+	/// Convert an `f64` into this dtype, rounding if needed
+	fn from_f64(value: f64) -> Self;
+	
+	/// Return the modulus of the complex value `real + i·imaginary` with [`Complex::abs`]
+	fn complex_abs(real: Self, imaginary: Self) -> Self;
+}
+
+/// Complex floating point field trait
+///
+/// Implemented for the [real fields][RealField] themselves and for [`Complex`] values of them. The
+/// [conjugate][Scalar::conj] and [square root][Scalar::sqrt] come from [`Scalar`].
+pub trait ComplexField: Field {
+	/// The real field the real and imaginary parts belong to
+	type Real: RealField;
+	
+	/// Make a value from its real and imaginary parts
+	///
+	/// Real fields ignore the imaginary part
+	fn from_parts(real: Self::Real, imaginary: Self::Real) -> Self;
+	
+	/// Return the real part
+	fn real_part(&self) -> Self::Real;
+	
+	/// Return the imaginary part, which is zero for real fields
+	fn imaginary_part(&self) -> Self::Real;
+	
+	/// Return the absolute value, or modulus, as a real value
+	fn magnitude(&self) -> Self::Real;
+}
+
+/// Primitive integer trait
+///
+/// Implemented for `i8` to `i64` and `isize`, which can be used as the numerator and denominator
+/// of a [`Rational`]
+pub trait Integer: Scalar + Ord + Rem<Output=Self> {
+	/// Convert the value into an `f64`, rounding if needed
+	fn to_f64(&self) -> f64;
+	
+	/// Convert a whole `f64` into this dtype, or `None` if it's out of range
+	fn from_f64(value: f64) -> Option<Self>;
+}
+
+/// Implement [`Zero`], [`One`], and [`Ring`] for primitive numbers
+macro_rules! primitive_ring {
+	($($t:ty),*) => {$(
+		impl Zero for $t {
+			fn zero() -> Self {
+				0 as $t
+			}
+		}
+		
+		impl One for $t {
+			fn one() -> Self {
+				1 as $t
+			}
+		}
+		
+		impl Ring for $t {
+			fn from_usize(n: usize) -> Self {
+				n as $t
+			}
+		}
+	)*};
+}
+
+macro_rules! integer_scalar {
+	($($t:ty),*) => {$(
+		impl Scalar for $t {
+			const FIELD: bool = false;
+			const EPSILON: f64 = 0.;
+			
+			fn modulus(&self) -> f64 {
+				(*self as f64).abs()
+			}
+			
+			fn conj(&self) -> Self {
+				*self
+			}
+			
+			fn sqrt(&self) -> Self {
+				(*self as f64).sqrt() as $t
+			}
+			
+			fn to_complex(&self) -> Complex<f64> {
+				Complex::from_real(*self as f64)
+			}
+			
+			fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self> {
+				let rounded = value.real.round();
+				if value.imaginary.abs() > tolerance || (value.real - rounded).abs() > tolerance {
+					None
+				} else {
+					Some(rounded as $t)
+				}
+			}
+			
+			fn checked_add(&self, other: &Self) -> Option<Self> {
+				<$t>::checked_add(*self, *other)
+			}
+			
+			fn checked_mul(&self, other: &Self) -> Option<Self> {
+				<$t>::checked_mul(*self, *other)
+			}
+			
+			fn checked_sub(&self, other: &Self) -> Option<Self> {
+				<$t>::checked_sub(*self, *other)
+			}
+		}
+		
+		impl Integer for $t {
+			fn to_f64(&self) -> f64 {
+				*self as f64
+			}
+			
+			fn from_f64(value: f64) -> Option<Self> {
+				if value.fract() != 0. || value < <$t>::MIN as f64 || value >= -(<$t>::MIN as f64) {
+					None
+				} else {
+					Some(value as $t)
+				}
+			}
+		}
+	)*};
+}
+
+macro_rules! float_scalar {
+	($($t:ty),*) => {$(
+		impl Scalar for $t {
+			const FIELD: bool = true;
+			const EPSILON: f64 = <$t>::EPSILON as f64;
+			
+			fn modulus(&self) -> f64 {
+				self.abs() as f64
+			}
+			
+			fn conj(&self) -> Self {
+				*self
+			}
+			
+			fn sqrt(&self) -> Self {
+				<$t>::sqrt(*self)
+			}
+			
+			fn complex_sqrt(real: Self, imaginary: Self) -> Complex<Self> {
+				Complex { real, imaginary }.sqrt()
+			}
+			
+			fn to_complex(&self) -> Complex<f64> {
+				Complex::from_real(*self as f64)
+			}
+			
+			fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self> {
+				if value.imaginary.abs() > tolerance {
+					None
+				} else {
+					Some(value.real as $t)
+				}
+			}
+		}
+		
+		impl Field for $t {}
+		
+		impl RealField for $t {
+			fn abs(&self) -> Self {
+				<$t>::abs(*self)
+			}
+			
+			fn to_f64(&self) -> f64 {
+				*self as f64
+			}
+			
+			fn from_f64(value: f64) -> Self {
+				value as $t
+			}
+			
+			fn complex_abs(real: Self, imaginary: Self) -> Self {
+				Complex { real, imaginary }.abs()
+			}
+		}
+		
+		impl ComplexField for $t {
+			type Real = $t;
+			
+			fn from_parts(real: Self, _imaginary: Self) -> Self {
+				real
+			}
+			
+			fn real_part(&self) -> Self {
+				*self
+			}
+			
+			fn imaginary_part(&self) -> Self {
+				0.
+			}
+			
+			fn magnitude(&self) -> Self {
+				<$t>::abs(*self)
+			}
+		}
+	)*};
+}
+
+primitive_ring!(i8, i16, i32, i64, isize, f32, f64);
+integer_scalar!(i8, i16, i32, i64, isize);
+float_scalar!(f32, f64);
+
+impl<L: Copy + Debug + Zero> Zero for Complex<L> where Complex<L>: Add<Output=Self> {
+	fn zero() -> Self {
+		Complex { real: L::zero(), imaginary: L::zero() }
+	}
+}
+
+impl<L: Copy + Debug + Zero + One> One for Complex<L> where Complex<L>: Mul<Output=Self> {
+	fn one() -> Self {
+		Complex { real: L::one(), imaginary: L::zero() }
+	}
+}
+
+impl<L: Ring> Ring for Complex<L> {
+	fn from_usize(n: usize) -> Self {
+		Complex { real: L::from_usize(n), imaginary: L::zero() }
+	}
+}
+
+impl<L: Scalar + PartialOrd> Scalar for Complex<L> {
+	const FIELD: bool = L::FIELD;
+	const EPSILON: f64 = L::EPSILON;
+	
+	fn modulus(&self) -> f64 {
+		self.real.modulus().hypot(self.imaginary.modulus())
+	}
+	
+	fn conj(&self) -> Self {
+		Complex::conj(self)
+	}
+	
+	fn sqrt(&self) -> Self {
+		L::complex_sqrt(self.real, self.imaginary)
+	}
+	
+	fn to_complex(&self) -> Complex<f64> {
+		Complex {
+			real: self.real.to_complex().real,
+			imaginary: self.imaginary.to_complex().real
+		}
+	}
+	
+	fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self> {
+		Some(Self {
+			real: L::from_complex(Complex::from_real(value.real), tolerance)?,
+			imaginary: L::from_complex(Complex::from_real(value.imaginary), tolerance)?
+		})
+	}
+	
+	fn checked_add(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			real: self.real.checked_add(&other.real)?,
+			imaginary: self.imaginary.checked_add(&other.imaginary)?
+		})
+	}
+	
+	fn checked_mul(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			real: self.real.checked_mul(&other.real)?.checked_sub(&self.imaginary.checked_mul(&other.imaginary)?)?,
+			imaginary: self.real.checked_mul(&other.imaginary)?.checked_add(&self.imaginary.checked_mul(&other.real)?)?
+		})
+	}
+	
+	fn checked_sub(&self, other: &Self) -> Option<Self> {
+		Some(Self {
+			real: self.real.checked_sub(&other.real)?,
+			imaginary: self.imaginary.checked_sub(&other.imaginary)?
+		})
+	}
+}
+
+impl<L: Field + PartialOrd> Field for Complex<L> {}
+
+impl<L: RealField> ComplexField for Complex<L> {
+	type Real = L;
+	
+	fn from_parts(real: L, imaginary: L) -> Self {
+		Complex { real, imaginary }
+	}
+	
+	fn real_part(&self) -> L {
+		self.real
+	}
+	
+	fn imaginary_part(&self) -> L {
+		self.imaginary
+	}
+	
+	fn magnitude(&self) -> L {
+		L::complex_abs(self.real, self.imaginary)
+	}
+}
+
+impl<I: Integer> Zero for Rational<I> {
+	fn zero() -> Self {
+		Rational::from_integer(I::zero())
+	}
+}
+
+impl<I: Integer> One for Rational<I> {
+	fn one() -> Self {
+		Rational::from_integer(I::one())
+	}
+}
+
+impl<I: Integer> Ring for Rational<I> {
+	fn from_usize(n: usize) -> Self {
+		Rational::from_integer(I::from_usize(n))
+	}
+}
+
+impl<I: Integer> Scalar for Rational<I> {
+	const FIELD: bool = true;
+	const EPSILON: f64 = 0.;
+	
+	fn modulus(&self) -> f64 {
+		(self.numerator.to_f64() / self.denominator.to_f64()).abs()
+	}
+	
+	fn conj(&self) -> Self {
+		*self
+	}
+	
+	/// Return the square root of the numerator over the square root of the denominator
+	///
+	/// Each is rounded towards zero, so this is only exact when both are perfect squares
+	fn sqrt(&self) -> Self {
+		Rational::new(self.numerator.sqrt(), self.denominator.sqrt())
+	}
+	
+	fn to_complex(&self) -> Complex<f64> {
+		Complex::from_real(self.numerator.to_f64() / self.denominator.to_f64())
+	}
+	
+	/// Convert a complex float into the closest fraction within `tolerance`
+	///
+	/// This uses the continued fraction of the real part, so the result has the smallest
+	/// denominator it can. Returns `None` if the imaginary part is too large or the numerator or
+	/// denominator is out of range
+	fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self> {
+		if value.imaginary.abs() > tolerance || !value.real.is_finite() {
+			return None
+		}
+		let target = value.real;
+		let (mut numerator, mut previous_numerator) = (1., 0.);
+		let (mut denominator, mut previous_denominator) = (0., 1.);
+		let mut x = target;
+		loop {
+			let whole = x.floor();
+			(numerator, previous_numerator) = (whole * numerator + previous_numerator, numerator);
+			(denominator, previous_denominator) = (whole * denominator + previous_denominator, denominator);
+			let out = Rational::new(I::from_f64(numerator)?, I::from_f64(denominator)?);
+			if x == whole || (numerator / denominator - target).abs() <= tolerance {
+				return Some(out)
+			}
+			x = 1. / (x - whole)
+		}
+	}
+}
+
+impl<I: Integer> Field for Rational<I> {
+	fn inverse(&self) -> Self {
+		self.recip()
+	}
+}
+
+impl<const P: u64> Zero for ModP<P> {
+	fn zero() -> Self {
+		ModP::new(0)
+	}
+}
+
+impl<const P: u64> One for ModP<P> {
+	fn one() -> Self {
+		ModP::new(1)
+	}
+}
+
+impl<const P: u64> Ring for ModP<P> {
+	fn from_usize(n: usize) -> Self {
+		ModP::new(n as u64)
+	}
+}
+
+impl<const P: u64> Scalar for ModP<P> {
+	const FIELD: bool = true;
+	const EPSILON: f64 = 0.;
+	const NORMED: bool = false;
+	
+	/// Return the value as an `f64`
+	///
+	/// A finite field has no notion of size, so this is not a magnitude. It is only zero for zero,
+	/// which is all pivoting needs
+	fn modulus(&self) -> f64 {
+		self.0 as f64
+	}
+	
+	fn conj(&self) -> Self {
+		*self
+	}
+	
+	/// Return a square root of the value
+	///
+	/// This will panic if the value isn't a square modulo `P`, which is about half of the non-zero
+	/// values. See [`ModP::try_sqrt`]
+	fn sqrt(&self) -> Self {
+		self.try_sqrt().expect("The value has no square root modulo P")
+	}
+	
+	fn to_complex(&self) -> Complex<f64> {
+		Complex::from_real(self.0 as f64)
+	}
+	
+	fn from_complex(value: Complex<f64>, tolerance: f64) -> Option<Self> {
+		let rounded = value.real.round();
+		if value.imaginary.abs() > tolerance || (value.real - rounded).abs() > tolerance || !rounded.is_finite() {
+			None
+		} else {
+			Some(ModP::new((rounded as i128).rem_euclid(P as i128) as u64))
+		}
+	}
+}
+
+impl<const P: u64> Field for ModP<P> {
+	/// Return the multiplicative inverse
+	///
+	/// This will panic if the value is zero. See [`ModP::try_inverse`]
+	fn inverse(&self) -> Self {
+		self.try_inverse().expect("Cannot invert zero")
+	}
+}
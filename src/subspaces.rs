@@ -0,0 +1,254 @@
+//! Row echelon form, rank and bases for the four fundamental subspaces
+//!
+//! Every basis is found from the pivots of the reduced row echelon form. Integer dtypes are
+//! eliminated without division, so the bases they return stay integral
+
+use crate::prelude::{Matrix, Vector};
+use crate::scalar::Scalar;
+use crate::Error;
+
+/// Greatest common divisor of two integer values using Euclid's algorithm. The sign of the result
+/// is not normalised
+fn gcd<L: Scalar>(mut a: L, mut b: L) -> L {
+	let zero = L::zero();
+	while b != zero {
+		let remainder = a - (a / b) * b;
+		a = b;
+		b = remainder;
+	}
+	a
+}
+
+/// Divide an integer row by the greatest common divisor of its values so that its first non-zero
+/// value is positive
+fn primitive<const N: usize, L: Scalar>(row: &mut [L; N]) {
+	let zero = L::zero();
+	let mut divisor = row.iter().fold(zero, |a, b| gcd(a, *b));
+	if divisor == zero {
+		return
+	}
+	let first = row.iter().find(|v| **v != zero).copied().unwrap_or(zero);
+	if (first.to_complex().real < 0.) != (divisor.to_complex().real < 0.) {
+		divisor = -divisor
+	}
+	for value in row.iter_mut() {
+		*value = *value / divisor
+	}
+}
+
+impl<const T: usize, const N: usize, L: Scalar> Matrix<T, N, L> {
+	/// Reduce the matrix to reduced row echelon form and return the pivot columns
+	///
+	/// Values no larger than `tolerance` times the largest value in the matrix are treated as
+	/// zero. Integer dtypes use Bareiss' fraction-free elimination instead, dividing each update
+	/// exactly by the previous pivot so values stay as small as the minors of the matrix. Each row
+	/// is then divided by the greatest common divisor of its values. Returns [`Error::Overflow`]
+	/// if an integer value overflows the dtype.
+	fn echelon(&self, tolerance: f64) -> Result<(Self, Vec<usize>), Error> {
+		let zero = L::zero();
+		let tolerance = tolerance * self.max_abs();
+		let mut a = self.0;
+		let mut pivots = Vec::new();
+		let mut previous = L::one();
+		for col in 0..N {
+			let row = pivots.len();
+			if row == T {
+				break
+			}
+			let pivot = (row + 1..T).fold(row, |p, r| if a[r][col].modulus() > a[p][col].modulus() { r } else { p });
+			if a[pivot][col].modulus() <= tolerance {
+				for r in a.iter_mut().skip(row) {
+					r[col] = zero
+				}
+				continue
+			}
+			a.swap(row, pivot);
+			if L::FIELD {
+				let scale = a[row][col];
+				for value in a[row].iter_mut() {
+					*value = *value / scale
+				}
+			}
+			let pivot_row = a[row];
+			let scale = pivot_row[col];
+			for (r, values) in a.iter_mut().enumerate() {
+				let factor = values[col];
+				if r == row || (L::FIELD && factor == zero) {
+					continue
+				}
+				for (value, pivot_value) in values.iter_mut().zip(pivot_row) {
+					*value = if L::FIELD {
+						*value - factor * pivot_value
+					} else {
+						scale.checked_mul(value)
+							.and_then(|v| v.checked_sub(&factor.checked_mul(&pivot_value)?))
+							.ok_or(Error::Overflow)? / previous
+					}
+				}
+				values[col] = zero
+			}
+			previous = scale;
+			pivots.push(col);
+		}
+		if !L::FIELD {
+			for row in a.iter_mut() {
+				primitive(row)
+			}
+		}
+		Ok((Matrix::new(a), pivots))
+	}
+	
+	/// Return the default relative tolerance used to decide if a pivot is zero
+	fn subspace_tolerance() -> f64 {
+		T.max(N) as f64 * L::EPSILON
+	}
+	
+	/// [`echelon`][Matrix::echelon] with the default tolerance, panicking on integer overflow
+	fn reduce(&self) -> (Self, Vec<usize>) {
+		self.echelon(Self::subspace_tolerance()).expect("Integer overflow in fraction-free elimination")
+	}
+	
+	/// Return the reduced row echelon form of the matrix
+	///
+	/// Each row starts with a pivot, which is the only non-zero value in its column, and the
+	/// pivots move right going down the rows. Any zero rows are at the bottom.
+	///
+	/// For float dtypes this uses Gauss-Jordan elimination with partial pivoting, and values no
+	/// larger than `max(T, N)` times the machine epsilon relative to the largest value are treated
+	/// as zero. See [`rref_with_tolerance`][Matrix::rref_with_tolerance] to change this. Every pivot
+	/// is one.
+	///
+	/// For integer dtypes the result would usually not be an integer matrix, so each row is instead
+	/// scaled to the smallest integer multiple of itself. The pivots are then positive but not
+	/// necessarily one. The elimination is fraction-free, so values can grow to the size of the
+	/// minors of the matrix, and this will panic if they overflow the dtype. Use
+	/// [`try_rref`][Matrix::try_rref] to handle this instead.
+	/// ```
+	/// # use lineas::Matrix;
+	/// let a = Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]);
+	/// assert_eq!(a.dtype::<f64>().rref(), Matrix::new([[1., 2., 0.], [0., 0., 1.], [0., 0., 0.]]));
+	/// assert_eq!(Matrix::new([[2, 1], [4, 3]]).rref(), Matrix::new([[1, 0], [0, 1]]));
+	/// assert_eq!(Matrix::new([[2, 1, 1], [4, 3, 1]]).rref(), Matrix::new([[1, 0, 1], [0, 1, -1]]));
+	/// assert_eq!(Matrix::new([[2, 3]]).rref(), Matrix::new([[2, 3]]))
+	/// ```
+	pub fn rref(&self) -> Self {
+		self.reduce().0
+	}
+	
+	/// Return the reduced row echelon form of the matrix
+	///
+	/// This is the same as [`rref`][Matrix::rref] but returns [`Error::Overflow`] if an integer
+	/// value overflows the dtype
+	/// ```
+	/// # use lineas::{Error, Matrix};
+	/// let a = Matrix::new([[7i8, 11], [17, 19]]);
+	/// assert_eq!(a.try_rref(), Err(Error::Overflow));
+	/// assert_eq!(a.dtype::<i16>().try_rref(), Ok(Matrix::new([[1, 0], [0, 1]])))
+	/// ```
+	pub fn try_rref(&self) -> Result<Self, Error> {
+		Ok(self.echelon(Self::subspace_tolerance())?.0)
+	}
+	
+	/// Return the reduced row echelon form of the matrix with a given pivot tolerance
+	///
+	/// This is the same as [`rref`][Matrix::rref] but values no larger than `tolerance` times the
+	/// largest value in the matrix are treated as zero. The tolerance is ignored for integer
+	/// dtypes which are always exact.
+	pub fn rref_with_tolerance(&self, tolerance: f64) -> Self {
+		self.echelon(tolerance).expect("Integer overflow in fraction-free elimination").0
+	}
+	
+	/// Return the rank of the matrix
+	///
+	/// This is the number of linearly independent rows or columns, which is the number of pivots
+	/// in the [reduced row echelon form][Matrix::rref]. Like [`rref`][Matrix::rref], this will
+	/// panic if an integer value overflows the dtype.
+	/// ```
+	/// # use lineas::Matrix;
+	/// assert_eq!(Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]).rank(), 2)
+	/// ```
+	pub fn rank(&self) -> usize {
+		self.reduce().1.len()
+	}
+	
+	/// Return a basis for the null space of the matrix
+	///
+	/// These are linearly independent vectors `x` such that `Ax=0`, one for each column without a
+	/// pivot in the [reduced row echelon form][Matrix::rref]. For float dtypes the value of each
+	/// vector in its free column is one, and for integer dtypes each vector is scaled to the
+	/// smallest integer multiple of itself.
+	/// ```
+	/// # use lineas::{Matrix, Vector};
+	/// let a = Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]);
+	/// assert_eq!(a.null_space(), vec![Vector::new([[-2, 1, 0]])])
+	/// ```
+	pub fn null_space(&self) -> Vec<Vector<N, L>> {
+		let zero = L::zero();
+		let one = L::one();
+		let (a, pivots) = self.reduce();
+		(0..N).filter(|c| !pivots.contains(c)).map(|free| {
+			let mut out = [zero; N];
+			if L::FIELD {
+				out[free] = one;
+				for (r, c) in pivots.iter().enumerate() {
+					out[*c] = -a[(r, free)]
+				}
+			} else {
+				let multiple = pivots.iter().enumerate()
+					.filter(|(r, _)| a[(*r, free)] != zero)
+					.fold(one, |m, (r, c)| m / gcd(m, a[(r, *c)]) * a[(r, *c)]);
+				out[free] = multiple;
+				for (r, c) in pivots.iter().enumerate() {
+					out[*c] = -a[(r, free)] * (multiple / a[(r, *c)])
+				}
+				primitive(&mut out);
+				if out[free].to_complex().real < 0. {
+					for value in out.iter_mut() {
+						*value = -*value
+					}
+				}
+			}
+			Vector::new([out])
+		}).collect()
+	}
+	
+	/// Return a basis for the column space of the matrix
+	///
+	/// These are the columns of the matrix that have a pivot in the
+	/// [reduced row echelon form][Matrix::rref]
+	/// ```
+	/// # use lineas::{Matrix, Vector};
+	/// let a = Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]);
+	/// assert_eq!(a.column_space(), vec![Vector::new([[1, 2, 3]]), Vector::new([[1, 0, 1]])])
+	/// ```
+	pub fn column_space(&self) -> Vec<Vector<T, L>> {
+		let (_, pivots) = self.reduce();
+		pivots.iter().map(|c| Vector::new([std::array::from_fn(|r| self[(r, *c)])])).collect()
+	}
+	
+	/// Return a basis for the row space of the matrix
+	///
+	/// These are the non-zero rows of the [reduced row echelon form][Matrix::rref]
+	/// ```
+	/// # use lineas::{Matrix, Vector};
+	/// let a = Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]);
+	/// assert_eq!(a.row_space(), vec![Vector::new([[1, 2, 0]]), Vector::new([[0, 0, 1]])])
+	/// ```
+	pub fn row_space(&self) -> Vec<Vector<N, L>> {
+		let (a, pivots) = self.reduce();
+		(0..pivots.len()).map(|r| Vector::new([a.0[r]])).collect()
+	}
+	
+	/// Return a basis for the left null space of the matrix
+	///
+	/// These are linearly independent vectors `y` such that `yA=0`, which is the
+	/// [null space][Matrix::null_space] of the transpose
+	/// ```
+	/// # use lineas::{Matrix, Vector};
+	/// let a = Matrix::new([[1, 2, 1], [2, 4, 0], [3, 6, 1]]);
+	/// assert_eq!(a.left_null_space(), vec![Vector::new([[-1, -1, 1]])])
+	/// ```
+	pub fn left_null_space(&self) -> Vec<Vector<T, L>> {
+		self.transpose().null_space()
+	}
+}
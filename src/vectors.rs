@@ -0,0 +1,351 @@
+//! Vector algebra for [`Vector`], [`RowVector`] and [`ColVector`]
+//!
+//! The dot product conjugates its left hand side, so complex vectors use the Hermitian inner
+//! product, and the lengths and angles built on it are measured in the same way.
+
+use crate::Complex;
+use std::fmt::Debug;
+use crate::prelude::{ColVector, Matrix, RowVector, Vector};
+use crate::scalar::{assert_normed, Scalar};
+
+/// Convert a real value into a dtype, rounding for integer dtypes
+///
+/// Exact dtypes such as [`Rational`][crate::Rational] get the simplest value within rounding error
+/// of `value`, so `1/3` comes back as itself rather than the fraction closest to its `f64`
+pub(crate) fn real<L: Scalar>(value: f64) -> L {
+	let z = Complex::from_real(value);
+	L::from_complex(z, value.abs() * f64::EPSILON).or_else(|| L::from_complex(z, f64::INFINITY)).unwrap()
+}
+
+impl<const T: usize, L: Scalar> Matrix<1, T, L> {
+	/// Return the inner product of two vectors
+	///
+	/// For complex dtypes the first vector is conjugated, so `a.dot(&a)` is the real squared
+	/// length of `a` and `a.dot(&b)` is the conjugate of `b.dot(&a)`
+	/// ```
+	/// # use lineas::{Complex, Vector};
+	/// assert_eq!(Vector::new([[1, 2, 3]]).dot(&Vector::new([[4, -5, 6]])), 12);
+	/// let a = Vector::new([[Complex::from_imaginary(1), Complex::from_real(1)]]);
+	/// assert_eq!(a.dot(&a), Complex::from_real(2))
+	/// ```
+	pub fn dot(&self, other: &Self) -> L {
+		self.0[0].iter().zip(other.0[0].iter()).fold(L::zero(), |a, (x, y)| a + x.conj() * *y)
+	}
+	
+	/// Return the Euclidean length of the vector
+	/// ```
+	/// # use lineas::Vector;
+	/// assert_eq!(Vector::new([[3, 4]]).norm(), 5.)
+	/// ```
+	pub fn norm(&self) -> f64 {
+		self.norm_frobenius()
+	}
+	
+	/// Return the `p`-norm of the vector
+	///
+	/// This is `(∑|xᵢ|ᵖ)^(1/p)`, which is the [Euclidean length][Matrix::norm] for `p=2`. Using
+	/// `f64::INFINITY` gives the largest absolute value.
+	/// ```
+	/// # use lineas::Vector;
+	/// let a = Vector::new([[3, -4]]);
+	/// assert_eq!(a.norm_p(1.), 7.);
+	/// assert_eq!(a.norm_p(f64::INFINITY), 4.)
+	/// ```
+	pub fn norm_p(&self, p: f64) -> f64 {
+		assert_normed::<L>();
+		if p == f64::INFINITY {
+			self.max_abs()
+		} else {
+			self.0[0].iter().map(|v| v.modulus().powf(p)).sum::<f64>().powf(p.recip())
+		}
+	}
+	
+	/// Return the unit vector in the same direction
+	///
+	/// Returns `None` for the zero vector, or if the result can't be represented in the dtype. The
+	/// length is calculated as an `f64`, so for exact dtypes such as integers and
+	/// [`Rational`][crate::Rational] this is only `Some` if the result has a length of exactly one.
+	/// ```
+	/// # use lineas::{Rational, Vector};
+	/// assert_eq!(Vector::new([[3., 4.]]).normalize(), Some(Vector::new([[0.6, 0.8]])));
+	/// assert_eq!(Vector::new([[0., 0.]]).normalize(), None);
+	/// let a = Vector::new([[3, 4]]).dtype::<Rational<i64>>();
+	/// assert_eq!(a.normalize(), Some(Vector::new([[Rational::new(3, 5), Rational::new(4, 5)]])));
+	/// assert_eq!(Vector::new([[1, 1]]).dtype::<Rational<i64>>().normalize(), None)
+	/// ```
+	#[doc(alias="normalise")]
+	pub fn normalize(&self) -> Option<Self> {
+		let norm = self.norm();
+		if norm == 0. {
+			return None
+		}
+		let mut out = *self;
+		for value in out.0[0].iter_mut() {
+			let scaled = value.to_complex() / Complex::from_real(norm);
+			*value = L::from_complex(scaled, L::EPSILON.max(scaled.abs() * f64::EPSILON))?
+		}
+		if L::EPSILON == 0. && out.dot(&out) != L::one() {
+			return None
+		}
+		Some(out)
+	}
+	
+	/// Return the angle between two vectors in radians
+	///
+	/// This is between `0` and `π`. For complex dtypes this uses the real part of the
+	/// [inner product][Matrix::dot], which treats each vector as a real vector of twice the length.
+	/// This is `NaN` if either vector is zero.
+	///
+	/// This uses `2atan2(‖|b|a-|a|b‖, ‖|b|a+|a|b‖)` rather than the arccosine of the normalised inner
+	/// product, which loses accuracy for nearly parallel vectors.
+	/// ```
+	/// # use lineas::Vector;
+	/// let angle = Vector::new([[1, 0]]).angle_between(&Vector::new([[1, 1]]));
+	/// assert!((angle - std::f64::consts::FRAC_PI_4).abs() < 1e-15)
+	/// ```
+	pub fn angle_between(&self, other: &Self) -> f64 {
+		let (norm, other_norm) = (self.norm(), other.norm());
+		if norm == 0. || other_norm == 0. {
+			return f64::NAN
+		}
+		let length = |sign: f64| self.0[0].iter().zip(other.0[0].iter()).map(|(x, y)| {
+			let z = Complex::from_real(other_norm) * x.to_complex() + Complex::from_real(sign * norm) * y.to_complex();
+			z.real.hypot(z.imaginary).powi(2)
+		}).sum::<f64>().sqrt();
+		2. * length(-1.).atan2(length(1.))
+	}
+	
+	/// Return the projection of the vector onto another vector
+	///
+	/// This is the component of the vector in the direction of `other`, which must not be zero
+	/// ```
+	/// # use lineas::Vector;
+	/// let a = Vector::new([[2., 3.]]);
+	/// assert_eq!(a.project_onto(&Vector::new([[1., 0.]])), Vector::new([[2., 0.]]))
+	/// ```
+	///
+	/// > We recommend using floats since integer division truncates
+	pub fn project_onto(&self, other: &Self) -> Self {
+		let scale = other.dot(self) / other.dot(other);
+		let mut out = *other;
+		for value in out.0[0].iter_mut() {
+			*value = scale * *value
+		}
+		out
+	}
+	
+	/// Return the rejection of the vector from another vector
+	///
+	/// This is the component of the vector perpendicular to `other`, so the
+	/// [projection][Matrix::project_onto] and rejection add up to the original vector. `other`
+	/// must not be zero.
+	/// ```
+	/// # use lineas::Vector;
+	/// let a = Vector::new([[2., 3.]]);
+	/// assert_eq!(a.reject_from(&Vector::new([[1., 0.]])), Vector::new([[0., 3.]]))
+	/// ```
+	///
+	/// > We recommend using floats since integer division truncates
+	pub fn reject_from(&self, other: &Self) -> Self {
+		*self - self.project_onto(other)
+	}
+	
+	/// Return the outer product of two vectors
+	///
+	/// This is the matrix with `aᵢbⱼ` in row `i` and column `j`. For complex dtypes the second
+	/// vector is conjugated, so `a.outer(&a)` is Hermitian.
+	/// ```
+	/// # use lineas::{Matrix, Vector};
+	/// let a = Vector::new([[1, 2]]);
+	/// assert_eq!(a.outer(&Vector::new([[3, 4, 5]])), Matrix::new([[3, 4, 5], [6, 8, 10]]))
+	/// ```
+	pub fn outer<const K: usize>(&self, other: &Vector<K, L>) -> Matrix<T, K, L> {
+		let mut out = Matrix::empty();
+		for i in 0..T {
+			for j in 0..K {
+				out[(i, j)] = self[(0, i)] * other[(0, j)].conj()
+			}
+		}
+		out
+	}
+}
+
+impl<L: Scalar> Matrix<1, 3, L> {
+	/// Return the cross product of two vectors
+	///
+	/// This is only defined for vectors of length 3. The result is perpendicular to both vectors
+	/// and its length is the area of the parallelogram they span.
+	/// ```
+	/// # use lineas::Vector;
+	/// let a = Vector::new([[1, 0, 0]]);
+	/// assert_eq!(a.cross(&Vector::new([[0, 1, 0]])), Vector::new([[0, 0, 1]]))
+	/// ```
+	pub fn cross(&self, other: &Self) -> Self {
+		let [a, b] = [self.0[0], other.0[0]];
+		Matrix::new([[
+			a[1] * b[2] - a[2] * b[1],
+			a[2] * b[0] - a[0] * b[2],
+			a[0] * b[1] - a[1] * b[0]
+		]])
+	}
+}
+
+impl<const T: usize, const N: usize, L: Scalar> Matrix<T, N, L> {
+	/// Return the matrix of absolute values
+	///
+	/// For complex dtypes this is the modulus of each value, which is rounded for integer parts
+	/// ```
+	/// # use lineas::{Rational, Vector};
+	/// assert_eq!(Vector::new([[-1, 2, -3]]).abs(), Vector::new([[1, 2, 3]]));
+	/// assert_eq!(Vector::new([[Rational::new(-3, 2)]]).abs(), Vector::new([[Rational::new(3, 2)]]))
+	/// ```
+	pub fn abs(&self) -> Self {
+		Matrix::new(self.0.map(|row| row.map(|v| {
+			let z = v.to_complex();
+			if z.imaginary != 0. {
+				real(v.modulus())
+			} else if z.real < 0. {
+				-v
+			} else {
+				v
+			}
+		})))
+	}
+	
+	/// Return the element-wise minimum of two matrices
+	/// ```
+	/// # use lineas::Vector;
+	/// assert_eq!(Vector::new([[1, 5, -3]]).min(&Vector::new([[2, 4, -6]])), Vector::new([[1, 4, -6]]))
+	/// ```
+	pub fn min(&self, other: &Self) -> Self where L: PartialOrd {
+		let mut out = *self;
+		for (value, other) in out.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+			if *other < *value {
+				*value = *other
+			}
+		}
+		out
+	}
+	
+	/// Return the element-wise maximum of two matrices
+	/// ```
+	/// # use lineas::Vector;
+	/// assert_eq!(Vector::new([[1, 5, -3]]).max(&Vector::new([[2, 4, -6]])), Vector::new([[2, 5, -3]]))
+	/// ```
+	pub fn max(&self, other: &Self) -> Self where L: PartialOrd {
+		let mut out = *self;
+		for (value, other) in out.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+			if *other > *value {
+				*value = *other
+			}
+		}
+		out
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> RowVector<T, L> {
+	/// Generate a new row vector from an array of values
+	/// ```
+	/// # use lineas::{Matrix, RowVector};
+	/// let v = RowVector::new([1, 2]);
+	/// assert_eq!(v * Matrix::new([[1, 0, 1], [0, 1, 1]]), RowVector::new([1, 2, 3]))
+	/// ```
+	pub fn new(data: [L; T]) -> Self {
+		Self(data)
+	}
+	
+	/// Transpose the row vector into a column vector
+	pub fn transpose(&self) -> ColVector<T, L> {
+		ColVector(self.0)
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> ColVector<T, L> {
+	/// Generate a new column vector from an array of values
+	/// ```
+	/// # use lineas::{ColVector, Matrix};
+	/// let v = ColVector::new([1, 2]);
+	/// assert_eq!(Matrix::new([[1, 0], [0, 1], [1, 1]]) * v, ColVector::new([1, 2, 3]))
+	/// ```
+	pub fn new(data: [L; T]) -> Self {
+		Self(data)
+	}
+	
+	/// Transpose the column vector into a row vector
+	pub fn transpose(&self) -> RowVector<T, L> {
+		RowVector(self.0)
+	}
+}
+
+/// Implement the [`Vector`] inner product and lengths for a vector wrapper by delegating to
+/// `Vector<T, L>`, which stores the same values in the same order
+macro_rules! wrapper_vector_ops {
+	($($t:ident),*) => {$(
+		impl<const T: usize, L: Scalar> $t<T, L> {
+			/// Return the inner product of two vectors
+			///
+			/// This is the same as [`Vector::dot`][Matrix::dot]
+			/// ```
+			#[doc = concat!("# use lineas::", stringify!($t), ";")]
+			#[doc = concat!("assert_eq!(", stringify!($t), "::new([1, 2, 3]).dot(&", stringify!($t), "::new([4, -5, 6])), 12)")]
+			/// ```
+			pub fn dot(&self, other: &Self) -> L {
+				Vector::new([self.0]).dot(&Vector::new([other.0]))
+			}
+			
+			/// Return the Euclidean length of the vector
+			///
+			/// This is the same as [`Vector::norm`][Matrix::norm]
+			pub fn norm(&self) -> f64 {
+				Vector::new([self.0]).norm()
+			}
+			
+			/// Return the unit vector in the same direction
+			///
+			/// This is the same as [`Vector::normalize`][Matrix::normalize], returning `None` for
+			/// the zero vector or if the result can't be represented in the dtype
+			#[doc(alias="normalise")]
+			pub fn normalize(&self) -> Option<Self> {
+				Some(Self(Vector::new([self.0]).normalize()?.0[0]))
+			}
+		}
+	)*};
+}
+
+wrapper_vector_ops!(RowVector, ColVector);
+
+impl<const T: usize, L: Copy + Debug> From<RowVector<T, L>> for Matrix<1, T, L> {
+	fn from(value: RowVector<T, L>) -> Self {
+		Matrix::new([value.0])
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> From<Matrix<1, T, L>> for RowVector<T, L> {
+	fn from(value: Matrix<1, T, L>) -> Self {
+		Self(value.0[0])
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> From<ColVector<T, L>> for Matrix<T, 1, L> {
+	fn from(value: ColVector<T, L>) -> Self {
+		Matrix::new(value.0.map(|v| [v]))
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> From<Matrix<T, 1, L>> for ColVector<T, L> {
+	fn from(value: Matrix<T, 1, L>) -> Self {
+		Self(value.0.map(|[v]| v))
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> From<ColVector<T, L>> for RowVector<T, L> {
+	fn from(value: ColVector<T, L>) -> Self {
+		value.transpose()
+	}
+}
+
+impl<const T: usize, L: Copy + Debug> From<RowVector<T, L>> for ColVector<T, L> {
+	fn from(value: RowVector<T, L>) -> Self {
+		value.transpose()
+	}
+}
@@ -0,0 +1,88 @@
+use lineas::{ColVector, Matrix, RowVector};
+
+#[cfg(test)]
+mod submatrix {
+	use super::*;
+	
+	#[test]
+	fn corners() {
+		let a = Matrix::new([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+		assert_eq!(a.submatrix::<2, 2>(0, 0), Matrix::new([[1, 2], [5, 6]]));
+		assert_eq!(a.submatrix::<2, 3>(1, 1), Matrix::new([[6, 7, 8], [10, 11, 12]]));
+		assert_eq!(a.submatrix::<3, 4>(0, 0), a);
+		assert_eq!(a.submatrix::<1, 1>(2, 3), Matrix::new([[12]]))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		Matrix::new([[1, 2], [3, 4]]).submatrix::<2, 2>(1, 0);
+	}
+	
+	#[test]
+	fn set_block() {
+		let a = Matrix::new([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+		let mut b = Matrix::empty();
+		b.set_block(0, 0, &a.submatrix::<3, 2>(0, 0));
+		b.set_block(0, 2, &a.submatrix::<3, 2>(0, 2));
+		assert_eq!(a, b);
+		b.set_block(1, 1, &Matrix::<2, 2, i32>::identity());
+		assert_eq!(b, Matrix::new([[1, 2, 3, 4], [5, 1, 0, 8], [9, 0, 1, 12]]))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn set_block_out_of_bounds() {
+		Matrix::<2, 2, i32>::empty().set_block(0, 1, &Matrix::new([[1, 2]]))
+	}
+	
+	#[test]
+	fn rows_and_columns() {
+		let a = Matrix::new([[1., 2., 3.], [4., 5., 6.]]);
+		assert_eq!(a.row(0), RowVector::new([1., 2., 3.]));
+		assert_eq!(a.column(2), ColVector::new([3., 6.]));
+		assert_eq!(a.row(1).transpose(), a.transpose().column(1))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn column_out_of_bounds() {
+		Matrix::new([[1, 2], [3, 4]]).column(2);
+	}
+}
+
+#[cfg(test)]
+mod stacking {
+	use super::*;
+	
+	#[test]
+	fn hstack() {
+		let a = Matrix::new([[1, 2], [3, 4]]);
+		let b = Matrix::new([[5, 6, 7], [8, 9, 10]]);
+		let c: Matrix<2, 5, _> = a.hstack(&b);
+		assert_eq!(c, Matrix::new([[1, 2, 5, 6, 7], [3, 4, 8, 9, 10]]));
+		assert_eq!(c.submatrix::<2, 2>(0, 0), a);
+		assert_eq!(c.submatrix::<2, 3>(0, 2), b)
+	}
+	
+	#[test]
+	fn vstack() {
+		let a = Matrix::new([[1, 2], [3, 4]]);
+		let b = Matrix::new([[5, 6]]);
+		let c: Matrix<3, 2, _> = a.vstack(&b);
+		assert_eq!(c, Matrix::new([[1, 2], [3, 4], [5, 6]]));
+		let d: Matrix<2, 3, _> = a.transpose().hstack(&b.transpose());
+		assert_eq!(c.transpose(), d)
+	}
+	
+	#[test]
+	fn swap() {
+		let mut a = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+		a.swap_rows(0, 2);
+		assert_eq!(a, Matrix::new([[7, 8, 9], [4, 5, 6], [1, 2, 3]]));
+		a.swap_cols(1, 2);
+		assert_eq!(a, Matrix::new([[7, 9, 8], [4, 6, 5], [1, 3, 2]]));
+		a.swap_rows(1, 1);
+		assert_eq!(a.determinant(), Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).determinant())
+	}
+}
@@ -0,0 +1,79 @@
+use lineas::{BoxedMatrix, DMatrix, Error, Matrix};
+use lineas::decompose::{CholeskyDecompose, LDLDecompose, LUDecompose, PLUDecompose, PLUQDecompose, QRDecompose};
+
+#[cfg(test)]
+mod ops {
+	use super::*;
+	
+	#[test]
+	fn arithmetic() {
+		let a = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+		let b = Matrix::new([[7, -8], [9, 0], [1, -2]]);
+		let c = Matrix::new([[1, 0, 2], [0, 3, -1]]);
+		assert_eq!(BoxedMatrix::from(a) + BoxedMatrix::from(b), BoxedMatrix::from(a + b));
+		assert_eq!(&BoxedMatrix::from(a) - &BoxedMatrix::from(b), BoxedMatrix::from(a - b));
+		assert_eq!(BoxedMatrix::from(a) * BoxedMatrix::from(c), BoxedMatrix::from(a * c));
+		assert_eq!(-BoxedMatrix::from(a), BoxedMatrix::from(-a));
+		assert_eq!(BoxedMatrix::from(a).transpose(), BoxedMatrix::from(a.transpose()));
+		assert_eq!(BoxedMatrix::from(a).scale(2), BoxedMatrix::from(a.scale(2)));
+		assert_eq!(BoxedMatrix::from(a).dtype::<f64>(), BoxedMatrix::from(a.dtype::<f64>()))
+	}
+	
+	#[test]
+	fn large() {
+		let a = BoxedMatrix::<512, 512, f64>::from_fn(|i, j| if i == j { 2. } else if i + 1 == j { 1. } else { 0. });
+		let b = BoxedMatrix::<512, 512, f64>::from_fn(|i, j| (i * 512 + j) as f64);
+		let product = &a * &b;
+		assert_eq!(product[(0, 0)], 512.);
+		assert_eq!(product[(511, 511)], 2. * (511. * 512. + 511.));
+		assert_eq!(product - b.scale(2.), BoxedMatrix::from_fn(|i, j| if i < 511 { ((i + 1) * 512 + j) as f64 } else { 0. }));
+		assert_eq!(a.determinant(), 2f64.powi(512))
+	}
+	
+	#[test]
+	fn determinant() {
+		let a = Matrix::new([[2, -3, 1, 4], [2, 0, -1, 1], [1, 4, 5, 0], [3, 1, 1, 2]]);
+		assert_eq!(BoxedMatrix::from(a).determinant(), a.determinant());
+		assert_eq!(BoxedMatrix::<3, 3, i32>::identity().determinant(), 1)
+	}
+	
+	#[test]
+	fn conversions() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+		assert_eq!(Matrix::from(BoxedMatrix::from(a)), a);
+		assert_eq!(DMatrix::from(BoxedMatrix::from(a)), DMatrix::from(a));
+		assert_eq!(BoxedMatrix::<2, 3, _>::try_from(DMatrix::from(a)), Ok(BoxedMatrix::from(a)));
+		assert_eq!(BoxedMatrix::<3, 2, i32>::try_from(DMatrix::from(a)), Err(Error::DimensionMismatch { expected: (3, 2), found: (2, 3) }))
+	}
+}
+
+#[cfg(test)]
+mod decompose {
+	use super::*;
+	
+	#[test]
+	fn elimination() {
+		let a = Matrix::new([[4., 3., 2.], [6., 3., 1.], [2., 5., 7.]]);
+		let (l, u) = a.lu_decompose().unwrap();
+		assert_eq!(BoxedMatrix::from(a).lu_decompose(), Ok((BoxedMatrix::from(l), BoxedMatrix::from(u))));
+		let (p, l, u) = a.plu_decompose().unwrap();
+		assert_eq!(BoxedMatrix::from(a).plu_decompose(), Ok((BoxedMatrix::from(p), BoxedMatrix::from(l), BoxedMatrix::from(u))));
+		let (p, l, u, q) = a.pluq_decompose().unwrap();
+		assert_eq!(BoxedMatrix::from(a).pluq_decompose(), Ok((BoxedMatrix::from(p), BoxedMatrix::from(l), BoxedMatrix::from(u), BoxedMatrix::from(q))))
+	}
+	
+	#[test]
+	fn symmetric() {
+		let a = Matrix::new([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+		assert_eq!(BoxedMatrix::from(a).cholesky_decompose(), a.cholesky_decompose().map(BoxedMatrix::from));
+		let (l, d) = a.ldl_decompose().unwrap();
+		assert_eq!(BoxedMatrix::from(a).ldl_decompose(), Ok((BoxedMatrix::from(l), BoxedMatrix::from(d))))
+	}
+	
+	#[test]
+	fn qr() {
+		let a = Matrix::new([[3., 0.], [4., 5.], [0., 4.]]);
+		let (q, r) = a.qr_decompose();
+		assert_eq!(BoxedMatrix::from(a).qr_decompose(), (BoxedMatrix::from(q), BoxedMatrix::from(r)))
+	}
+}
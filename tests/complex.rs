@@ -1,4 +1,14 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use lineas::Complex;
+use lineas::scalar::Scalar;
+
+fn c(real: f64, imaginary: f64) -> Complex<f64> {
+	Complex::from_complex(real, imaginary)
+}
+
+fn close(a: Complex<f64>, b: Complex<f64>) -> bool {
+	(a - b).abs() < 1e-12
+}
 
 #[cfg(test)]
 mod ops {
@@ -38,3 +48,156 @@ mod ops {
 		assert_eq!(lhs1, rhs)
 	}
 }
+
+#[cfg(test)]
+mod functions {
+	use super::*;
+	
+	#[test]
+	fn polar() {
+		let z = c(-3., 4.);
+		assert_eq!(z.abs(), 5.);
+		assert_eq!(z.norm_sqr(), 25.);
+		assert_eq!(c(-1., 0.).arg(), PI);
+		assert_eq!(c(-1., -0.).arg(), -PI);
+		let (r, theta) = z.to_polar();
+		assert!(close(Complex::<f64>::from_polar(r, theta), z));
+		assert!(close(Complex::<f64>::from_polar(2., FRAC_PI_2), c(0., 2.)))
+	}
+	
+	#[test]
+	fn exponential() {
+		assert!(close(c(0., PI).exp(), c(-1., 0.)));
+		assert!(close(c(1., FRAC_PI_2).exp(), c(0., std::f64::consts::E)));
+		assert!(close(c(-1., 0.).ln(), c(0., PI)));
+		assert!(close(c(0., 1.).ln(), c(0., FRAC_PI_2)));
+		for z in [c(1., 2.), c(-3., 0.5), c(0.1, -4.)] {
+			assert!(close(z.ln().exp(), z))
+		}
+	}
+	
+	#[test]
+	fn roots() {
+		assert_eq!(c(-4., 0.).sqrt(), c(0., 2.));
+		assert_eq!(c(-4., -0.).sqrt(), c(0., -2.));
+		assert_eq!(c(0., 0.).sqrt(), c(0., 0.));
+		assert!(close(c(0., 2.).sqrt(), c(1., 1.)));
+		assert!(close(c(3., -4.).sqrt(), c(2., -1.)));
+		for z in [c(1e-20, 1.), c(-5., 1e-10), c(-1e10, -3.)] {
+			let root = z.sqrt();
+			assert!(root.real() >= 0.);
+			assert!((root * root - z).abs() <= 1e-12 * z.abs())
+		}
+		for z in [c(-4., 0.), c(-4., -0.), c(-0., 0.), c(-1e10, -3.), c(3e200, 4e200), c(0., -2.)] {
+			assert_eq!(Scalar::sqrt(&z), z.sqrt())
+		}
+		let z = Complex::from_complex(-4f32, -0.);
+		assert_eq!(Scalar::sqrt(&z), z.sqrt());
+		assert_eq!(Scalar::sqrt(&Complex::from_complex(-4, 0)), Complex::from_complex(0, 2))
+	}
+	
+	#[test]
+	fn powers() {
+		assert!(close(c(1., 1.).powf(2.), c(0., 2.)));
+		assert!(close(c(-8., 0.).powf(1. / 3.), c(1., 3f64.sqrt())));
+		assert!(close(c(0., 1.).powc(c(0., 1.)), c((-FRAC_PI_2).exp(), 0.)));
+		assert!(close(c(2., 0.).powc(c(3., 0.)), c(8., 0.)));
+		assert_eq!(c(0., 0.).powf(2.), c(0., 0.));
+		assert_eq!(c(0., 0.).powf(0.), c(1., 0.));
+		assert_eq!(c(0., 0.).powc(c(0., 0.)), c(1., 0.));
+		assert_eq!(c(0., 0.).powc(c(2., 5.)), c(0., 0.));
+		assert!(c(0., 0.).powc(c(-1., 0.)).real().is_nan())
+	}
+	
+	#[test]
+	fn reciprocal() {
+		assert_eq!(c(0., 2.).recip(), c(0., -0.5));
+		assert!(close(c(3., 4.).recip() * c(3., 4.), c(1., 0.)));
+		assert_eq!(Complex::<f64>::roots_of_unity(0), Vec::new());
+		assert_eq!(Complex::<f64>::roots_of_unity(1), vec![c(1., 0.)]);
+		let roots = Complex::<f64>::roots_of_unity(6);
+		assert!(close(roots[2], c(-0.5, 3f64.sqrt() / 2.)));
+		for root in roots {
+			assert!(close(root.powf(6.), c(1., 0.)))
+		}
+	}
+	
+	#[test]
+	fn trigonometric() {
+		assert!(close(c(FRAC_PI_2, 0.).sin(), c(1., 0.)));
+		assert!(close(c(0., 1.).sin(), c(0., 1f64.sinh())));
+		assert!(close(c(PI, 0.).cos(), c(-1., 0.)));
+		assert!(close(c(0., 1.).cos(), c(1f64.cosh(), 0.)));
+		assert!(close(c(FRAC_PI_4, 0.).tan(), c(1., 0.)));
+		assert!(close(c(0., 1.).tan(), c(0., 1f64.tanh())));
+		for z in [c(0.5, -1.5), c(-2., 0.3)] {
+			let (sin, cos) = (z.sin(), z.cos());
+			assert!(close(sin * sin + cos * cos, c(1., 0.)));
+			assert!(close(z.tan(), sin / cos))
+		}
+	}
+	
+	#[test]
+	fn hyperbolic() {
+		assert!(close(c(0., FRAC_PI_2).sinh(), c(0., 1.)));
+		assert!(close(c(0., PI).cosh(), c(-1., 0.)));
+		assert!(close(c(1., 0.).tanh(), c(1f64.tanh(), 0.)));
+		for z in [c(0.5, -1.5), c(-2., 0.3)] {
+			let (sinh, cosh) = (z.sinh(), z.cosh());
+			assert!(close(cosh * cosh - sinh * sinh, c(1., 0.)));
+			assert!(close(z.tanh(), sinh / cosh))
+		}
+	}
+	
+	#[test]
+	fn inverses() {
+		assert!(close(c(1., 0.).asin(), c(FRAC_PI_2, 0.)));
+		assert!(close(c(-1., 0.).acos(), c(PI, 0.)));
+		assert!(close(c(1., 0.).atan(), c(FRAC_PI_4, 0.)));
+		assert!(close(c(0., 1.).asinh(), c(0., FRAC_PI_2)));
+		assert!(close(c(1., 0.).acosh(), c(0., 0.)));
+		assert!(close(c(0., 1.).atanh(), c(0., FRAC_PI_4)));
+		for z in [c(0.3, 0.4), c(-1.2, 2.), c(2., -0.7)] {
+			assert!(close(z.asin().sin(), z));
+			assert!(close(z.acos().cos(), z));
+			assert!(close(z.atan().tan(), z));
+			assert!(close(z.asinh().sinh(), z));
+			assert!(close(z.acosh().cosh(), z));
+			assert!(close(z.atanh().tanh(), z))
+		}
+	}
+	
+	#[test]
+	fn branch_cuts() {
+		let x = 2f64.acosh();
+		let e = 1e-14;
+		// Each value on a cut matches the limit from the documented side
+		assert!(close(c(2., 0.).asin(), c(FRAC_PI_2, -x)));
+		assert!(close(c(2., -e).asin(), c(FRAC_PI_2, -x)));
+		assert!(close(c(2., e).asin(), c(FRAC_PI_2, x)));
+		assert!(close(c(-2., 0.).asin(), c(-FRAC_PI_2, x)));
+		assert!(close(c(-2., e).asin(), c(-FRAC_PI_2, x)));
+		assert!(close(c(2., 0.).acos(), c(0., x)));
+		assert!(close(c(-2., 0.).acos(), c(PI, -x)));
+		assert!(close(c(0., 2.).atan(), c(FRAC_PI_2, 3f64.ln() / 2.)));
+		assert!(close(c(e, 2.).atan(), c(FRAC_PI_2, 3f64.ln() / 2.)));
+		assert!(close(c(0., -2.).atan(), c(-FRAC_PI_2, -3f64.ln() / 2.)));
+		assert!(close(c(0., 2.).asinh(), c(x, FRAC_PI_2)));
+		assert!(close(c(0., -2.).asinh(), c(-x, -FRAC_PI_2)));
+		assert!(close(c(-2., 0.).acosh(), c(x, PI)));
+		assert!(close(c(-2., e).acosh(), c(x, PI)));
+		assert!(close(c(2., 0.).atanh(), c(3f64.ln() / 2., -FRAC_PI_2)));
+		assert!(close(c(-2., 0.).atanh(), c(-3f64.ln() / 2., FRAC_PI_2)));
+		assert!(close(c(-1., 0.).sqrt(), c(0., 1.)));
+		assert!(close(c(-1., -e).sqrt(), c(0., -1.)))
+	}
+	
+	#[test]
+	fn single_precision() {
+		let z = Complex::from_complex(-4f32, 0.);
+		assert_eq!(z.sqrt(), Complex::from_complex(0., 2.));
+		assert!((z.ln().exp() - z).abs() < 1e-5);
+		assert!((Complex::from_complex(0.3f32, 0.4).asin().sin() - Complex::from_complex(0.3, 0.4)).abs() < 1e-5);
+		assert_eq!(Complex::<f32>::roots_of_unity(3).len(), 3)
+	}
+}
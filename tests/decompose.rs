@@ -1,5 +1,6 @@
-use lineas::Matrix;
+use lineas::{Error, Matrix};
 use lineas::decompose::*;
+use lineas::scalar::Scalar;
 
 #[cfg(test)]
 mod lu_decompose {
@@ -19,7 +20,7 @@ mod lu_decompose {
 	#[test]
 	fn incorrect() {
 		let a = Matrix::new([[0, 1], [1, 1]]);
-		assert!(a.lu_decompose().is_none())
+		assert_eq!(a.lu_decompose(), Err(Error::ZeroPivot { row: 0, col: 0 }))
 	}
 }
 
@@ -40,9 +41,648 @@ mod plu_decompose {
 		assert_eq!(p * l * u, a)
 	}
 	
+	#[test]
+	fn inexact() {
+		assert_eq!(Matrix::new([[2, 1], [1, 3]]).plu_decompose(), Err(Error::InexactDivision));
+		assert_eq!(Matrix::new([[2, 1], [1, 3]]).pluq_decompose(), Err(Error::InexactDivision))
+	}
+	
+	#[test]
+	fn largest_pivot() {
+		let a = Matrix::new([[1, 2, 2], [4, 4, 2], [4, 6, 4]]).dtype::<f64>();
+		let p_check = Matrix::new([[0, 0, 1], [1, 0, 0], [0, 1, 0]]).dtype::<f64>();
+		let (p, l, u) = a.plu_decompose().unwrap();
+		assert_eq!(p, p_check);
+		for r in 0..3 {
+			assert_eq!(u[(r, r)], 1.);
+			for c in 0..r {
+				assert!(l[(r, c)].abs() <= l[(c, c)].abs())
+			}
+		}
+		assert_eq!(p * l * u, a)
+	}
+	
+	#[test]
+	fn machine_precision() {
+		let a = Matrix::new([
+			[0.3f64, -1.7, 2.2, 9.1, 0.05],
+			[4.4, 0.0, -3.3, 1.0, 7.25],
+			[-6.1, 2.8, 0.9, -0.4, 1.5],
+			[1.2, 5.5, -2.0, 3.3, -8.8],
+			[0.0, -0.6, 4.7, 2.1, 3.9]
+		]);
+		let (p, l, u) = a.plu_decompose().unwrap();
+		let diff = p * l * u - a;
+		for r in 0..5 {
+			for c in 0..5 {
+				assert!(diff[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([[1, 2, 3], [2, 4, 6], [0, 1, 1]]).dtype::<f64>();
+		assert!(a.plu_decompose().is_err())
+	}
+}
+
+#[cfg(test)]
+mod pluq_decompose {
+	use super::*;
+	
+	#[test]
+	fn correct() {
+		let a = Matrix::new([[1, 2, 2], [4, 4, 2], [4, 6, 4]]).dtype::<f64>();
+		let p_check = Matrix::new([[0, 0, 1], [0, 1, 0], [1, 0, 0]]).dtype::<f64>();
+		let q_check = Matrix::new([[0, 1, 0], [1, 0, 0], [0, 0, 1]]).dtype::<f64>();
+		let (p, l, u, q) = a.pluq_decompose().unwrap();
+		assert_eq!(p, p_check);
+		assert_eq!(q, q_check);
+		let diff = p * l * u * q - a;
+		for r in 0..3 {
+			for c in 0..3 {
+				assert!(diff[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([[1, 2, 3], [2, 4, 6], [3, 6, 9]]).dtype::<f64>();
+		assert!(a.pluq_decompose().is_err())
+	}
+	
 	// #[test]
 	// fn incorrect() {
 	// 	let a = Matrix::new([[0, 1], [1, 1]]);
-	// 	assert!(a.lu_decompose().is_none())
+	// 	assert!(a.lu_decompose().is_err())
 	// }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod diagonalise {
+	use super::*;
+	use lineas::Complex;
+	
+	fn assert_close<const T: usize>(lhs: Matrix<T, T, f64>, rhs: Matrix<T, T, f64>) {
+		for r in 0..T {
+			for c in 0..T {
+				assert!((lhs[(r, c)] - rhs[(r, c)]).abs() < 1e-10, "{} != {}", lhs, rhs)
+			}
+		}
+	}
+	
+	#[test]
+	fn distinct() {
+		let a = Matrix::new([[4, 1], [2, 3]]).dtype::<f64>();
+		let (p, d, p_inv) = a.diagonalise().unwrap();
+		assert_close(d, Matrix::new([[2., 0.], [0., 5.]]));
+		assert_close(p * d * p_inv, a);
+		assert_close(p * p_inv, Matrix::identity())
+	}
+	
+	#[test]
+	fn repeated() {
+		let a = Matrix::new([[3, 0, 1], [0, 2, 0], [1, 0, 3]]).dtype::<f64>();
+		let (p, d, p_inv) = a.diagonalise().unwrap();
+		assert_close(d, Matrix::new([[2., 0., 0.], [0., 2., 0.], [0., 0., 4.]]));
+		assert_close(p * d * p_inv, a)
+	}
+	
+	#[test]
+	fn identity() {
+		let a: Matrix<3, 3, f64> = Matrix::identity();
+		assert_eq!(a.diagonalise(), Some((a, a, a)))
+	}
+	
+	#[test]
+	fn defective() {
+		assert!(Matrix::new([[1., 1.], [0., 1.]]).diagonalise().is_none());
+		assert!(Matrix::new([[2., 1.], [-1., 0.]]).diagonalise().is_none());
+		assert!(Matrix::new([[1., 1., 0.], [-1., 3., 0.], [0., 0., 2.]]).diagonalise().is_none())
+	}
+	
+	#[test]
+	fn tolerance() {
+		let a = Matrix::new([[1., 1.], [0., 1. + 1e-9]]);
+		assert!(a.diagonalise().is_none());
+		let (p, d, p_inv) = a.diagonalise_with_tolerance(1e-12).unwrap();
+		assert_close(p * d * p_inv, a)
+	}
+	
+	#[test]
+	fn integer() {
+		assert!(Matrix::new([[4, 1], [2, 3]]).diagonalise().is_none());
+		let (p, d, p_inv) = Matrix::new([[4, 1], [2, 3]]).dtype::<f64>().diagonalise().unwrap();
+		assert_close(p * d * p_inv, Matrix::new([[4., 1.], [2., 3.]]));
+		let a = Matrix::new([[3, 0], [0, 2]]);
+		assert_eq!(a.diagonalise(), Some((Matrix::new([[0, 1], [1, 0]]), Matrix::new([[2, 0], [0, 3]]), Matrix::new([[0, 1], [1, 0]]))))
+	}
+	
+	#[test]
+	fn real_complex_eigenvalues() {
+		let a = Matrix::new([[0., -1.], [1., 0.]]);
+		assert!(a.diagonalise().is_none())
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_real(0.), Complex::from_real(-1.)],
+			[Complex::from_real(1.), Complex::from_real(0.)]
+		]);
+		let (p, d, p_inv) = a.diagonalise().unwrap();
+		let check = p * d * p_inv - a;
+		assert!((d[(0, 0)] - Complex::from_imaginary(-1.)).modulus() < 1e-12);
+		assert!((d[(1, 1)] - Complex::from_imaginary(1.)).modulus() < 1e-12);
+		for r in 0..2 {
+			for c in 0..2 {
+				assert!(check[(r, c)].modulus() < 1e-12)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod solve {
+	use super::*;
+	use lineas::{Error, Vector};
+	
+	#[test]
+	fn single() {
+		let a = Matrix::new([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]).dtype::<f64>();
+		let b = Matrix::new([[8.], [-11.], [-3.]]);
+		let x = a.solve(&b).unwrap();
+		let check = x - Matrix::new([[2.], [3.], [-1.]]);
+		for r in 0..3 {
+			assert!(check[(r, 0)].abs() < 1e-14)
+		}
+	}
+	
+	#[test]
+	fn vector() {
+		let a = Matrix::new([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]).dtype::<f64>();
+		let b = Vector::new([[8., -11., -3.]]);
+		let x = a.solve_vector(&b).unwrap();
+		let check = x - Vector::new([[2., 3., -1.]]);
+		for c in 0..3 {
+			assert!(check[(0, c)].abs() < 1e-14)
+		}
+	}
+	
+	#[test]
+	fn multiple() {
+		let a = Matrix::new([
+			[0.3f64, -1.7, 2.2, 9.1],
+			[4.4, 0.0, -3.3, 1.0],
+			[-6.1, 2.8, 0.9, -0.4],
+			[1.2, 5.5, -2.0, 3.3]
+		]);
+		let b = Matrix::new([[1., 0.5, -2.], [2., 0., 3.], [3., -1., 1.], [4., 2.5, 0.]]);
+		let check = a * a.solve(&b).unwrap() - b;
+		for r in 0..4 {
+			for c in 0..3 {
+				assert!(check[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn reuse() {
+		let a = Matrix::new([[4, -2, 1], [3, 6, -4], [2, 1, 8]]).dtype::<f64>();
+		let factorisation = a.plu_factorise().unwrap();
+		let check = factorisation.permutation() * factorisation.lower() * factorisation.upper() - a;
+		for r in 0..3 {
+			for c in 0..3 {
+				assert!(check[(r, c)].abs() < 1e-14)
+			}
+		}
+		for b in [Matrix::new([[1.], [0.], [0.]]), Matrix::new([[12.], [-25.], [32.]]), Matrix::new([[0.], [-3.], [7.]])] {
+			let check = a * factorisation.solve(&b) - b;
+			for r in 0..3 {
+				assert!(check[(r, 0)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([[1, 2], [2, 4]]).dtype::<f64>();
+		assert_eq!(a.solve(&Matrix::new([[1.], [2.]])), Err(Error::SingularMatrix));
+		assert_eq!(a.plu_factorise(), Err(Error::SingularMatrix));
+		assert!(PLUFactorisation::with_tolerance(&Matrix::new([[1., 2.], [1., 2. + 1e-10]]), 1e-8).is_err())
+	}
+	
+	#[test]
+	fn integer() {
+		let a = Matrix::new([[2, 1], [1, 3]]);
+		assert_eq!(a.solve(&Matrix::new([[3, 1], [4, -7]])), Ok(Matrix::new([[1, 2], [1, -3]])));
+		assert_eq!(a.solve_vector(&Matrix::new([[3, 4]])), Ok(Matrix::new([[1, 1]])));
+		assert_eq!(a.solve(&Matrix::new([[1], [1]])), Err(Error::InexactDivision));
+		assert_eq!(Matrix::new([[1, 2], [2, 4]]).solve(&Matrix::new([[1], [2]])), Err(Error::SingularMatrix));
+		assert_eq!(a.plu_factorise(), Err(Error::InexactDivision))
+	}
+}
+
+#[cfg(test)]
+mod qr_decompose {
+	use super::*;
+	use lineas::Complex;
+	
+	/// Check `QᴴQ == I` for a matrix with orthonormal columns
+	fn assert_orthonormal<const T: usize, const N: usize, L: Scalar>(q: Matrix<T, N, L>) {
+		for a in 0..N {
+			for b in 0..N {
+				let dot = (0..T).fold(Matrix::<1, 1, L>::empty()[(0, 0)], |s, i| s + q[(i, a)].conj() * q[(i, b)]);
+				let expected = if a == b { 1. } else { 0. };
+				assert!((dot.to_complex() - Complex::from_real(expected)).modulus() < 1e-14)
+			}
+		}
+	}
+	
+	fn assert_upper<const T: usize, const N: usize, L: Scalar>(r: Matrix<T, N, L>) {
+		for i in 0..T {
+			for j in 0..i.min(N) {
+				assert_eq!(r[(i, j)].modulus(), 0.)
+			}
+		}
+	}
+	
+	fn assert_close<const T: usize, const N: usize, L: Scalar>(lhs: Matrix<T, N, L>, rhs: Matrix<T, N, L>) {
+		for i in 0..T {
+			for j in 0..N {
+				assert!((lhs[(i, j)] - rhs[(i, j)]).modulus() < 1e-13)
+			}
+		}
+	}
+	
+	#[test]
+	fn tall() {
+		let a = Matrix::new([[12, -51, 4], [6, 167, -68], [-4, 24, -41], [1, 1, 1]]).dtype::<f64>();
+		let (q, r) = a.qr_decompose();
+		assert_orthonormal(q);
+		assert_upper(r);
+		assert_close(q * r, a)
+	}
+	
+	#[test]
+	fn wide() {
+		let a = Matrix::new([[2, -1, 0, 3], [1, 4, -2, 1]]).dtype::<f64>();
+		let (q, r) = a.qr_decompose();
+		assert_orthonormal(q);
+		assert_upper(r);
+		assert_close(q * r, a)
+	}
+	
+	#[test]
+	fn known() {
+		let a = Matrix::new([[12, -51, 4], [6, 167, -68], [-4, 24, -41]]).dtype::<f64>();
+		let (q, r) = a.qr_decompose();
+		assert_close(r, Matrix::new([[14., 21., -14.], [0., 175., -70.], [0., 0., 35.]]));
+		assert_close(q.scale(175.), Matrix::new([[150., -69., -58.], [75., 158., 6.], [-50., 30., -165.]]))
+	}
+	
+	#[test]
+	fn thin() {
+		let a = Matrix::new([[1, 2], [3, 4], [5, 6]]).dtype::<f64>();
+		let (q, r) = a.thin_qr_decompose();
+		let (full_q, full_r) = a.qr_decompose();
+		assert_orthonormal(q);
+		assert_upper(r);
+		assert_close(q * r, a);
+		assert_eq!(r[(1, 1)], full_r[(1, 1)]);
+		assert_eq!(q[(2, 1)], full_q[(2, 1)])
+	}
+	
+	#[test]
+	fn gram_schmidt() {
+		let a = Matrix::new([[12, -51, 4], [6, 167, -68], [-4, 24, -41], [1, 1, 1]]).dtype::<f64>();
+		let (q, r) = a.gram_schmidt_qr_decompose().unwrap();
+		let (thin_q, thin_r) = a.thin_qr_decompose();
+		assert_orthonormal(q);
+		assert_upper(r);
+		assert_close(q * r, a);
+		assert_close(q, thin_q);
+		assert_close(r, thin_r)
+	}
+	
+	#[test]
+	fn gram_schmidt_dependent() {
+		let a = Matrix::new([[1, 2], [2, 4], [3, 6]]).dtype::<f64>();
+		assert_eq!(a.gram_schmidt_qr_decompose(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_real(2.), Complex::from_imaginary(-3.)],
+			[Complex::from_imaginary(2.), Complex::from_complex(0., -1.), Complex::from_real(1.)],
+			[Complex::from_real(-1.), Complex::from_complex(4., 2.), Complex::from_complex(1., 1.)]
+		]);
+		let (q, r) = a.qr_decompose();
+		assert_orthonormal(q);
+		assert_upper(r);
+		for k in 0..3 {
+			assert_eq!(r[(k, k)].imag(), 0.);
+			assert!(r[(k, k)].real() > 0.)
+		}
+		assert_close(q * r, a);
+		let (q, r) = a.gram_schmidt_qr_decompose().unwrap();
+		assert_orthonormal(q);
+		assert_close(q * r, a)
+	}
+}
+
+#[cfg(test)]
+mod cholesky_decompose {
+	use super::*;
+	use lineas::Complex;
+	
+	#[test]
+	fn correct() {
+		let a = Matrix::new([[25, 15, -5], [15, 18, 0], [-5, 0, 11]]).dtype::<f64>();
+		let l = a.cholesky_decompose().unwrap();
+		assert_eq!(l, Matrix::new([[5., 0., 0.], [3., 3., 0.], [-1., 1., 3.]]));
+		assert_eq!(l * l.transpose(), a)
+	}
+	
+	#[test]
+	fn not_symmetric() {
+		let a = Matrix::new([[4, 1], [2, 3]]).dtype::<f64>();
+		assert_eq!(a.cholesky_decompose(), Err(Error::NotSymmetric))
+	}
+	
+	#[test]
+	fn not_positive_definite() {
+		let a = Matrix::new([[1, 2], [2, 1]]).dtype::<f64>();
+		assert_eq!(a.cholesky_decompose(), Err(Error::NotPositiveDefinite));
+		let b = Matrix::new([[1, 1], [1, 1]]).dtype::<f64>();
+		assert_eq!(b.cholesky_decompose(), Err(Error::NotPositiveDefinite))
+	}
+	
+	#[test]
+	fn hermitian() {
+		let a = Matrix::new([
+			[Complex::from_real(4.), Complex::from_complex(2., -2.)],
+			[Complex::from_complex(2., 2.), Complex::from_real(6.)]
+		]);
+		let l = a.cholesky_decompose().unwrap();
+		let check = Matrix::new([
+			[Complex::from_real(2.), Complex::from_real(0.)],
+			[Complex::from_complex(1., 1.), Complex::from_real(2.)]
+		]);
+		assert_eq!(l, check);
+		let mut l_h = l.transpose();
+		for i in 0..2 {
+			for j in 0..2 {
+				l_h[(i, j)] = l_h[(i, j)].conj()
+			}
+		}
+		assert_eq!(l * l_h, a)
+	}
+}
+
+#[cfg(test)]
+mod ldl_decompose {
+	use super::*;
+	
+	#[test]
+	fn positive_definite() {
+		let a = Matrix::new([[4, 12, -16], [12, 37, -43], [-16, -43, 98]]).dtype::<f64>();
+		let (l, d) = a.ldl_decompose().unwrap();
+		assert_eq!(l, Matrix::new([[1., 0., 0.], [3., 1., 0.], [-4., 5., 1.]]));
+		assert_eq!(d, Matrix::new([[4., 0., 0.], [0., 1., 0.], [0., 0., 9.]]));
+		assert_eq!(l * d * l.transpose(), a)
+	}
+	
+	#[test]
+	fn indefinite() {
+		let a = Matrix::new([[2, -1, 3], [-1, -4, 1], [3, 1, 0]]).dtype::<f64>();
+		let (l, d) = a.ldl_decompose().unwrap();
+		assert!(d[(1, 1)] < 0.);
+		let check = l * d * l.transpose() - a;
+		for r in 0..3 {
+			for c in 0..3 {
+				assert!(check[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn zero_pivot() {
+		let a = Matrix::new([[0, 1], [1, 0]]).dtype::<f64>();
+		assert_eq!(a.ldl_decompose(), Err(Error::ZeroPivot { row: 0, col: 0 }))
+	}
+	
+	#[test]
+	fn not_symmetric() {
+		let a = Matrix::new([[1, 2], [3, 1]]).dtype::<f64>();
+		assert_eq!(a.ldl_decompose(), Err(Error::NotSymmetric))
+	}
+}
+
+#[cfg(test)]
+mod sv_decompose {
+	use super::*;
+	use lineas::{Complex, Vector};
+	
+	/// Check `QᴴQ == I` for a square matrix
+	fn assert_unitary<const T: usize, L: Scalar>(q: Matrix<T, T, L>) {
+		let zero = Matrix::<1, 1, L>::empty()[(0, 0)];
+		for i in 0..T {
+			for j in 0..T {
+				let dot = (0..T).fold(zero, |a, k| a + q[(k, i)].conj() * q[(k, j)]);
+				let expected = if i == j { 1. } else { 0. };
+				assert!((dot.to_complex() - Complex::from_real(expected)).modulus() < 1e-13)
+			}
+		}
+	}
+	
+	/// Check `U` and `Vᵀ` are orthogonal and `UΣVᵀ == A` with decreasing singular values
+	fn assert_svd<const T: usize, const N: usize, L: Scalar>(a: Matrix<T, N, L>, (u, s, vt): (Matrix<T, T, L>, Vector<N, L>, Matrix<N, N, L>)) {
+		assert_unitary(u);
+		assert_unitary(vt);
+		let mut sigma = Matrix::<T, N, L>::empty();
+		for k in 0..N {
+			assert_eq!(s[(0, k)].to_complex().imag(), 0.);
+			assert!(s[(0, k)].to_complex().real() >= 0.);
+			if k > 0 {
+				assert!(s[(0, k)].modulus() <= s[(0, k - 1)].modulus())
+			}
+			if k < T {
+				sigma[(k, k)] = s[(0, k)]
+			} else {
+				assert_eq!(s[(0, k)].modulus(), 0.)
+			}
+		}
+		let check = u * sigma * vt;
+		for i in 0..T {
+			for j in 0..N {
+				assert!((check[(i, j)] - a[(i, j)]).modulus() < 1e-12)
+			}
+		}
+	}
+	
+	#[test]
+	fn square() {
+		let a = Matrix::new([[3, 0], [4, 5]]).dtype::<f64>();
+		let svd = a.sv_decompose().unwrap();
+		assert!((svd.1[(0, 0)] - 45f64.sqrt()).abs() < 1e-14);
+		assert!((svd.1[(0, 1)] - 5f64.sqrt()).abs() < 1e-14);
+		assert_svd(a, svd)
+	}
+	
+	#[test]
+	fn tall() {
+		let a = Matrix::new([[12, -51, 4], [6, 167, -68], [-4, 24, -41], [1, 1, 1]]).dtype::<f64>();
+		assert_svd(a, a.sv_decompose().unwrap())
+	}
+	
+	#[test]
+	fn wide() {
+		let a = Matrix::new([[2, -1, 0, 3], [1, 4, -2, 1]]).dtype::<f64>();
+		let svd = a.sv_decompose().unwrap();
+		assert_eq!(svd.1[(0, 2)], 0.);
+		assert_eq!(svd.1[(0, 3)], 0.);
+		assert_svd(a, svd)
+	}
+	
+	#[test]
+	fn rank_deficient() {
+		let a = Matrix::new([[1, 2, 3], [2, 4, 6], [1, 0, 1]]).dtype::<f64>();
+		let svd = a.sv_decompose().unwrap();
+		assert!(svd.1[(0, 2)] < 1e-14);
+		assert_svd(a, svd)
+	}
+	
+	#[test]
+	fn zero() {
+		let a = Matrix::<3, 2, f64>::empty();
+		let svd = a.sv_decompose().unwrap();
+		assert_eq!(svd.1, Vector::empty());
+		assert_svd(a, svd)
+	}
+	
+	#[test]
+	fn diagonal() {
+		let a = Matrix::new([[1, 0, 0], [0, -3, 0], [0, 0, 2]]).dtype::<f64>();
+		let svd = a.sv_decompose().unwrap();
+		assert_eq!(svd.1, Vector::new([[3., 2., 1.]]));
+		assert_svd(a, svd)
+	}
+	
+	#[test]
+	fn larger() {
+		let a = Matrix::new([
+			[4, -2, 7, 1, 0, 3],
+			[-1, 5, 2, -6, 8, 0],
+			[3, 3, -4, 2, 1, -7],
+			[0, 9, 1, 1, -2, 4],
+			[6, -1, 0, 5, 3, 2],
+			[2, 2, 2, -3, -5, 1]
+		]).dtype::<f64>();
+		assert_svd(a, a.sv_decompose().unwrap())
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_real(2.), Complex::from_imaginary(-3.)],
+			[Complex::from_imaginary(2.), Complex::from_complex(0., -1.), Complex::from_real(1.)]
+		]);
+		assert_svd(a, a.sv_decompose().unwrap());
+		let b = Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_imaginary(2.)],
+			[Complex::from_real(2.), Complex::from_complex(0., -1.)],
+			[Complex::from_imaginary(-3.), Complex::from_real(1.)]
+		]);
+		assert_svd(b, b.sv_decompose().unwrap())
+	}
+	
+	#[test]
+	fn values_only() {
+		let a = Matrix::new([[12, -51, 4], [6, 167, -68], [-4, 24, -41], [1, 1, 1]]).dtype::<f64>();
+		let values = a.singular_values().unwrap();
+		let (_, s, _) = a.sv_decompose().unwrap();
+		for k in 0..3 {
+			assert!((values[(0, k)] - s[(0, k)]).abs() < 1e-12)
+		}
+	}
+}
+
+#[cfg(test)]
+mod symmetric_eigen {
+	use super::*;
+	
+	/// Check `QᵀQ == I` and `Q diag(λ) Qᵀ == A` with ascending eigenvalues
+	fn assert_eigen<const T: usize>(a: Matrix<T, T, f64>) {
+		let (values, q) = a.symmetric_eigen().unwrap();
+		let mut d = Matrix::<T, T, f64>::empty();
+		for k in 0..T {
+			d[(k, k)] = values[(0, k)];
+			if k > 0 {
+				assert!(values[(0, k - 1)] <= values[(0, k)])
+			}
+		}
+		let orthogonal = q.transpose() * q - Matrix::identity();
+		let check = q * d * q.transpose() - a;
+		for i in 0..T {
+			for j in 0..T {
+				assert!(orthogonal[(i, j)].abs() < 1e-13);
+				assert!(check[(i, j)].abs() < 1e-12)
+			}
+		}
+	}
+	
+	#[test]
+	fn simple() {
+		let a = Matrix::new([[2, 0, 0], [0, 3, 4], [0, 4, 9]]).dtype::<f64>();
+		let (values, q) = a.symmetric_eigen().unwrap();
+		let root = 5f64.sqrt();
+		let expected = Matrix::new([[0., 1., 0.], [2. / root, 0., 1. / root], [-1. / root, 0., 2. / root]]);
+		for i in 0..3 {
+			assert!((values[(0, i)] - [1., 2., 11.][i]).abs() < 1e-14);
+			for j in 0..3 {
+				assert!((q[(i, j)] - expected[(i, j)]).abs() < 1e-14)
+			}
+		}
+		assert_eigen(a)
+	}
+	
+	#[test]
+	fn dense() {
+		let b = Matrix::new([
+			[4, -2, 7, 1, 0, 3],
+			[-1, 5, 2, -6, 8, 0],
+			[3, 3, -4, 2, 1, -7],
+			[0, 9, 1, 1, -2, 4],
+			[6, -1, 0, 5, 3, 2],
+			[2, 2, 2, -3, -5, 1]
+		]).dtype::<f64>();
+		let a = b + b.transpose();
+		assert_eigen(a);
+		let (values, _) = a.symmetric_eigen().unwrap();
+		for (k, expected) in a.eigenvalues().unwrap().iter().enumerate() {
+			assert!((values[(0, k)] - expected.real()).abs() < 1e-12)
+		}
+	}
+	
+	#[test]
+	fn repeated() {
+		assert_eigen(Matrix::<4, 4, f64>::identity());
+		assert_eigen(Matrix::new([[2, 1, 1], [1, 2, 1], [1, 1, 2]]).dtype::<f64>())
+	}
+	
+	#[test]
+	fn single_precision() {
+		let a = Matrix::new([[2, 1], [1, 2]]).dtype::<f32>();
+		let (values, _) = a.symmetric_eigen().unwrap();
+		assert!((values[(0, 0)] - 1.).abs() < 1e-6 && (values[(0, 1)] - 3.).abs() < 1e-6)
+	}
+	
+	#[test]
+	fn not_symmetric() {
+		let a = Matrix::new([[1, 2], [3, 4]]).dtype::<f64>();
+		assert_eq!(a.symmetric_eigen(), Err(Error::NotSymmetric))
+	}
+}
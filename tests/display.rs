@@ -0,0 +1,54 @@
+use lineas::Matrix;
+
+#[cfg(test)]
+mod compact {
+	use super::*;
+	
+	#[test]
+	fn integer() {
+		let a = Matrix::new([[1, -2], [30, 4]]);
+		assert_eq!(format!("{}", a), "[[1, -2], [30, 4]]")
+	}
+	
+	#[test]
+	fn precision() {
+		let a = Matrix::new([[1, 2], [3, 4]]).dtype::<f64>().scale(0.5);
+		assert_eq!(format!("{:.2}", a), "[[0.50, 1.00], [1.50, 2.00]]")
+	}
+	
+	#[test]
+	fn width() {
+		let a = Matrix::new([[1, 2], [3, 4]]);
+		assert_eq!(format!("{:3}", a), "[[  1,   2], [  3,   4]]")
+	}
+}
+
+#[cfg(test)]
+mod pretty {
+	use super::*;
+	use lineas::Complex;
+	
+	#[test]
+	fn aligned() {
+		let a = Matrix::new([[1, -2, 3], [40, 5, -600], [7, 8, 9]]);
+		assert_eq!(format!("{:#}", a), "⎡  1  -2     3 ⎤\n⎢ 40   5  -600 ⎥\n⎣  7   8     9 ⎦")
+	}
+	
+	#[test]
+	fn single_row() {
+		let a = Matrix::new([[1, 2, 3]]);
+		assert_eq!(format!("{:#}", a), "[ 1  2  3 ]")
+	}
+	
+	#[test]
+	fn precision_width() {
+		let a = Matrix::new([[1, -2], [3, 4]]).dtype::<f32>();
+		assert_eq!(format!("{:#6.1}", a), "⎡    1.0    -2.0 ⎤\n⎣    3.0     4.0 ⎦")
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([[Complex::from_complex(1., 2.), Complex::from_real(-1.5)]]);
+		assert_eq!(format!("{:#.1}", a), "[ 1.0+2.0i  -1.5+0.0i ]")
+	}
+}
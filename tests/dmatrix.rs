@@ -0,0 +1,159 @@
+use lineas::{DMatrix, Error, Matrix};
+use lineas::decompose::{CholeskyDecompose, LDLDecompose, LUDecompose, PLUDecompose, PLUQDecompose, QRDecompose, SVDecompose};
+
+#[cfg(test)]
+mod construction {
+	use super::*;
+	
+	#[test]
+	fn constructors() {
+		let a = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+		assert_eq!(a, DMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+		assert_eq!(a, DMatrix::from_fn(2, 3, |i, j| (3 * i + j + 1) as i32));
+		assert_eq!(a.shape(), (2, 3));
+		assert_eq!(a[(1, 2)], 6);
+		assert_eq!(DMatrix::<i32>::identity(3), DMatrix::from(Matrix::<3, 3, i32>::identity()))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn wrong_length() {
+		DMatrix::new(2, 2, vec![1, 2, 3]);
+	}
+	
+	#[test]
+	#[should_panic]
+	fn ragged() {
+		DMatrix::from_rows(vec![vec![1, 2], vec![3]]);
+	}
+	
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let _ = DMatrix::new(2, 2, vec![1, 2, 3, 4])[(0, 2)];
+	}
+	
+	#[test]
+	fn conversions() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+		let b = DMatrix::from(a);
+		assert_eq!(b.as_slice(), &[1, 2, 3, 4, 5, 6]);
+		assert_eq!(Matrix::<2, 3, _>::try_from(b.clone()), Ok(a));
+		assert_eq!(Matrix::<3, 2, _>::try_from(b), Err(Error::DimensionMismatch { expected: (3, 2), found: (2, 3) }))
+	}
+}
+
+#[cfg(test)]
+mod ops {
+	use super::*;
+	
+	#[test]
+	fn arithmetic() {
+		let a = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+		let b = Matrix::new([[7, -8], [9, 0], [1, -2]]);
+		let c = Matrix::new([[1, 0, 2], [0, 3, -1]]);
+		assert_eq!(DMatrix::from(a) + DMatrix::from(b), DMatrix::from(a + b));
+		assert_eq!(&DMatrix::from(a) - &DMatrix::from(b), DMatrix::from(a - b));
+		assert_eq!(DMatrix::from(a) * DMatrix::from(c), DMatrix::from(a * c));
+		assert_eq!(-DMatrix::from(a), DMatrix::from(-a));
+		let mut d = DMatrix::from(a);
+		d += DMatrix::from(b);
+		d -= &DMatrix::from(a);
+		assert_eq!(d, DMatrix::from(b))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn add_mismatch() {
+		let _ = DMatrix::<i32>::empty(2, 3) + DMatrix::empty(3, 2);
+	}
+	
+	#[test]
+	#[should_panic]
+	fn mul_mismatch() {
+		let _ = DMatrix::<i32>::empty(2, 3) * DMatrix::empty(2, 3);
+	}
+	
+	#[test]
+	fn transformations() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+		assert_eq!(DMatrix::from(a).transpose(), DMatrix::from(a.transpose()));
+		assert_eq!(DMatrix::from(a).scale(3), DMatrix::from(a.scale(3)));
+		assert_eq!(DMatrix::from(a).dtype::<f64>(), DMatrix::from(a.dtype::<f64>()))
+	}
+	
+	#[test]
+	fn determinant() {
+		let a = Matrix::new([[2, -3, 1, 4], [2, 0, -1, 1], [1, 4, 5, 0], [3, 1, 1, 2]]);
+		assert_eq!(DMatrix::from(a).determinant(), a.determinant());
+		assert_eq!(DMatrix::from(a.dtype::<f64>()).determinant(), a.dtype::<f64>().determinant());
+		assert_eq!(DMatrix::from(Matrix::new([[1, 2], [3, 4]])).determinant(), -2)
+	}
+	
+	#[test]
+	#[should_panic]
+	fn determinant_not_square() {
+		DMatrix::<i32>::empty(2, 3).determinant();
+	}
+}
+
+#[cfg(test)]
+mod decompose {
+	use super::*;
+	
+	fn close(a: &DMatrix<f64>, b: &DMatrix<f64>) -> bool {
+		a.shape() == b.shape() && (a - b).max_abs() < 1e-12
+	}
+	
+	#[test]
+	fn lu() {
+		let a = Matrix::new([[4., 3., 2.], [6., 3., 1.], [2., 5., 7.]]);
+		let (l, u) = a.lu_decompose().unwrap();
+		assert_eq!(DMatrix::from(a).lu_decompose(), Ok((DMatrix::from(l), DMatrix::from(u))));
+		assert_eq!(DMatrix::<f64>::empty(2, 3).lu_decompose(), Err(Error::DimensionMismatch { expected: (2, 2), found: (2, 3) }))
+	}
+	
+	#[test]
+	fn plu() {
+		let a = Matrix::new([[1., 2., 2.], [4., 4., 2.], [4., 6., 4.]]);
+		let (p, l, u) = a.plu_decompose().unwrap();
+		assert_eq!(DMatrix::from(a).plu_decompose(), Ok((DMatrix::from(p), DMatrix::from(l), DMatrix::from(u))));
+		let (p, l, u, q) = a.pluq_decompose().unwrap();
+		assert_eq!(DMatrix::from(a).pluq_decompose(), Ok((DMatrix::from(p), DMatrix::from(l), DMatrix::from(u), DMatrix::from(q))));
+		assert_eq!(DMatrix::from(Matrix::new([[1., 2.], [2., 4.]])).plu_decompose(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn symmetric() {
+		let a = Matrix::new([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+		let l = DMatrix::from(a).cholesky_decompose().unwrap();
+		assert_eq!(l, DMatrix::from(a.cholesky_decompose().unwrap()));
+		let (l, d) = DMatrix::from(a).ldl_decompose().unwrap();
+		let expected = a.ldl_decompose().unwrap();
+		assert_eq!((l, d), (DMatrix::from(expected.0), DMatrix::from(expected.1)));
+		assert_eq!(DMatrix::<f64>::identity(2).cholesky_decompose(), Ok(DMatrix::identity(2)));
+		assert_eq!(DMatrix::<f64>::empty(2, 3).cholesky_decompose(), Err(Error::DimensionMismatch { expected: (2, 2), found: (2, 3) }));
+		assert_eq!(DMatrix::from(Matrix::new([[1., 2.], [3., 4.]])).ldl_decompose(), Err(Error::NotSymmetric))
+	}
+	
+	#[test]
+	fn qr() {
+		let a = Matrix::new([[1., 2., 0.], [3., -1., 2.]]);
+		let (q, r) = a.qr_decompose();
+		let (dq, dr) = DMatrix::from(a).qr_decompose();
+		assert!(close(&dq, &DMatrix::from(q)) && close(&dr, &DMatrix::from(r)));
+		let (q, r) = DMatrix::from(a.transpose()).qr_decompose();
+		assert!(close(&(q * r), &DMatrix::from(a.transpose())))
+	}
+	
+	#[test]
+	fn svd() {
+		let a = Matrix::new([[3., 0.], [4., 5.], [1., -2.]]);
+		let (u, s, vt) = a.sv_decompose().unwrap();
+		let (du, ds, dvt) = DMatrix::from(a).sv_decompose().unwrap();
+		assert!(close(&du, &DMatrix::from(u)) && close(&dvt, &DMatrix::from(vt)));
+		assert_eq!(ds, vec![s[(0, 0)], s[(0, 1)]]);
+		let values = DMatrix::from(a.transpose()).singular_values().unwrap();
+		assert!((values[0] - s[(0, 0)]).abs() < 1e-12 && (values[1] - s[(0, 1)]).abs() < 1e-12 && values[2] == 0.)
+	}
+}
@@ -0,0 +1,85 @@
+use lineas::{BoxedMatrix, Complex, DMatrix, Error, Matrix};
+use lineas::decompose::{LUDecompose, PLUDecompose, PLUQDecompose};
+use lineas::polynomials::Polynomial;
+
+#[cfg(test)]
+mod decompose {
+	use super::*;
+	
+	#[test]
+	fn zero_pivot() {
+		let a = Matrix::new([[1., 2., 3.], [2., 4., 1.], [0., 1., 1.]]);
+		assert_eq!(a.lu_decompose(), Err(Error::ZeroPivot { row: 1, col: 1 }));
+		assert!(a.plu_decompose().is_ok())
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([[1., 2.], [2., 4.]]);
+		assert_eq!(a.plu_decompose(), Err(Error::SingularMatrix));
+		assert_eq!(a.pluq_decompose(), Err(Error::SingularMatrix));
+		assert_eq!(BoxedMatrix::from(a).plu_decompose(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn not_square() {
+		let expected = Err(Error::DimensionMismatch { expected: (3, 3), found: (3, 2) });
+		assert_eq!(DMatrix::<f64>::empty(3, 2).pluq_decompose(), expected)
+	}
+}
+
+#[cfg(test)]
+mod conversion {
+	use super::*;
+	
+	#[test]
+	fn try_dtype() {
+		let a = Matrix::new([[1, -2], [3, 4]]);
+		assert_eq!(a.try_dtype::<i8>(), Ok(a.dtype::<i8>()));
+		assert_eq!(a.try_dtype::<u8>(), Err(Error::ConversionOverflow));
+		assert_eq!(BoxedMatrix::from(a).try_dtype::<u64>(), Err(Error::ConversionOverflow));
+		assert_eq!(DMatrix::from(a).try_dtype::<f64>(), Ok(DMatrix::from(a.dtype::<f64>())));
+		assert_eq!(Polynomial::new(vec![300, 1]).try_dtype::<u8>(), Err(Error::ConversionOverflow));
+		assert_eq!(Complex::from_complex(1, 1000).try_dtype::<i8>(), Err(Error::ConversionOverflow));
+		assert_eq!(Complex::from_complex(1, 100).try_dtype::<i8>(), Ok(Complex::from_complex(1i8, 100i8)))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn dtype_overflow() {
+		Matrix::new([[256]]).dtype::<u8>();
+	}
+}
+
+#[cfg(test)]
+mod polynomials {
+	use super::*;
+	
+	#[test]
+	fn real_roots() {
+		assert_eq!(Polynomial::new(vec![2, -4]).real_roots(), Ok(vec![2f32]));
+		assert_eq!(Polynomial::new(vec![0, 1, 0, 1]).real_roots(), Ok(Vec::new()));
+		assert_eq!(Polynomial::new(vec![1, 0, 0, 0, -1]).real_roots(), Err(Error::UnsupportedDegree(4)))
+	}
+	
+	#[test]
+	fn display() {
+		assert_eq!(Error::ZeroPivot { row: 1, col: 2 }.to_string(), "zero pivot at (1, 2)");
+		assert_eq!(Error::UnsupportedDegree(5).to_string(), "unsupported polynomial degree 5");
+		assert_eq!(Error::InexactDivision.to_string(), "division is not exact in the dtype");
+		assert_eq!(Error::Overflow.to_string(), "value overflowed the dtype");
+		assert_eq!(Error::NotSymmetric.to_string(), "matrix is not symmetric")
+	}
+}
+
+#[cfg(test)]
+mod eigen {
+	use super::*;
+	
+	#[test]
+	fn converges() {
+		let a = Matrix::new([[2., 0.], [0., 3.]]);
+		assert_eq!(a.eigenvalues(), Ok(vec![Complex::from_real(2.), Complex::from_real(3.)]));
+		assert_eq!(a.eigenvectors().map(|pairs| pairs.len()), Ok(2))
+	}
+}
@@ -0,0 +1,152 @@
+use lineas::{Error, Matrix, ModP};
+use lineas::decompose::{LDLDecompose, LUDecompose, PLUDecompose};
+use lineas::polynomials::Polynomial;
+use lineas::scalar::{Field, Ring, Scalar};
+
+type F7 = ModP<7>;
+
+fn m(value: u64) -> F7 {
+	ModP::new(value)
+}
+
+#[cfg(test)]
+mod arithmetic {
+	use super::*;
+	
+	#[test]
+	fn ops() {
+		assert_eq!(m(12), m(5));
+		assert_eq!(m(5) + m(4), m(2));
+		assert_eq!(m(2) - m(5), m(4));
+		assert_eq!(-m(3), m(4));
+		assert_eq!(-m(0), m(0));
+		assert_eq!(m(3) * m(5), m(1));
+		assert_eq!(m(2) / m(3), m(3));
+		assert_eq!(m(3).pow(6), m(1));
+		assert_eq!(F7::from_usize(23), m(2))
+	}
+	
+	#[test]
+	fn inverse() {
+		for value in 1..7 {
+			assert_eq!(m(value) * m(value).inverse(), m(1))
+		}
+		assert_eq!(m(0).try_inverse(), None);
+		let large = ModP::<{ (1 << 61) - 1 }>::new(123456789);
+		assert_eq!(large * large.inverse(), ModP::new(1));
+		let largest = ModP::<18446744073709551557>::new(u64::MAX);
+		assert_eq!(largest.value(), 58);
+		assert_eq!(largest * largest.inverse(), ModP::new(1))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn divide_by_zero() {
+		let _ = m(3) / m(0);
+	}
+	
+	#[test]
+	fn sqrt() {
+		for value in 0..41 {
+			let value = ModP::<41>::new(value);
+			if let Some(root) = value.try_sqrt() {
+				assert_eq!(root * root, value)
+			}
+		}
+		assert_eq!(m(2).sqrt(), m(3));
+		assert_eq!(m(3).try_sqrt(), None)
+	}
+	
+	#[test]
+	fn conversions() {
+		assert_eq!(Matrix::new([[-1, 8, 14]]).dtype::<F7>(), Matrix::new([[m(6), m(1), m(0)]]));
+		assert_eq!(format!("{}", Matrix::new([[m(3), m(10)]])), "[[3, 3]]");
+		assert_eq!(Polynomial::new(vec![m(1), m(6), m(3)]).to_string(), "+x²-x+3")
+	}
+}
+
+#[cfg(test)]
+mod matrices {
+	use super::*;
+	
+	#[test]
+	fn determinant() {
+		let a = Matrix::new([[2, -3, 1, 4], [2, 0, -1, 1], [1, 4, 5, 0], [3, 1, 1, 2]]);
+		assert_eq!(a.dtype::<F7>().determinant(), F7::from_usize(a.determinant() as usize));
+		assert_eq!(Matrix::new([[1, 2], [3, 6]]).dtype::<F7>().determinant(), m(0));
+		assert_eq!(Matrix::new([[1, 2], [3, 13]]).dtype::<F7>().determinant(), m(0))
+	}
+	
+	#[test]
+	fn decompose() {
+		let a = Matrix::new([[1, 2, 3], [2, 1, 1], [3, 1, 2]]).dtype::<F7>();
+		let (l, u) = a.lu_decompose().unwrap();
+		assert_eq!(l * u, a);
+		let (p, l, u) = a.plu_decompose().unwrap();
+		assert_eq!(p * l * u, a);
+		let (l, d) = a.ldl_decompose().unwrap();
+		assert_eq!(l * d * l.transpose(), a);
+		assert_eq!(d, Matrix::new([[1, 0, 0], [0, 4, 0], [0, 0, 6]]).dtype())
+	}
+	
+	#[test]
+	fn inverse() {
+		let a = Matrix::new([[1, 2, 3], [2, 1, 1], [3, 1, 2]]).dtype::<F7>();
+		assert_eq!(a * a.inverse(), Matrix::identity());
+		assert_eq!(Matrix::new([[1, 2], [4, 1]]).dtype::<F7>().try_inverse(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn rank() {
+		let a = Matrix::new([[1, 2, 3], [2, 4, 6], [1, 1, 1]]);
+		assert_eq!(a.dtype::<F7>().rank(), 2);
+		let b = Matrix::new([[1, 3], [2, 6], [1, 0]]).dtype::<ModP<3>>();
+		assert_eq!(b.rank(), 1);
+		assert_eq!(b.rref(), Matrix::new([[1, 0], [0, 0], [0, 0]]).dtype())
+	}
+	
+	#[test]
+	fn characteristic() {
+		let a = Matrix::new([[1, 1], [0, 1]]).dtype::<ModP<2>>();
+		assert_eq!(a.characteristic_polynomial(), Polynomial::new(vec![1, 0, 1]).dtype());
+		let b = Matrix::new([[3, 1, 0, 2], [1, 0, -2, 1], [4, 1, 1, 0], [0, 2, 1, -1]]);
+		assert_eq!(b.dtype::<ModP<3>>().characteristic_polynomial(), b.characteristic_polynomial().dtype())
+	}
+	
+	#[test]
+	fn adjugate() {
+		let a = Matrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]);
+		let b = a.dtype::<ModP<3>>();
+		assert_eq!(b.adjugate(), a.adjugate().dtype());
+		assert_eq!(b * b.adjugate(), Matrix::identity().scale(b.determinant()));
+		assert_eq!(Matrix::new([[1, 2], [3, 4]]).dtype::<ModP<2>>().adjugate(), Matrix::new([[0, 0], [1, 1]]).dtype())
+	}
+}
+
+#[cfg(test)]
+mod polynomials {
+	use super::*;
+	
+	fn p(coefficients: Vec<i64>) -> Polynomial<F7> {
+		Polynomial::new(coefficients).dtype()
+	}
+	
+	#[test]
+	fn arithmetic() {
+		assert_eq!(p(vec![1, 3]) + p(vec![6, 4]), Polynomial::new(Vec::new()));
+		assert_eq!(p(vec![1, 3]) * p(vec![1, 4]), p(vec![1, 0, 5]));
+		assert_eq!(p(vec![1, 0, 5]) - p(vec![1, 0, 5]), Polynomial::new(Vec::new()));
+		let (quotient, remainder) = p(vec![1, 0, 0, 1]).div_rem(&p(vec![2, 1]));
+		assert_eq!(quotient * p(vec![2, 1]) + remainder, p(vec![1, 0, 0, 1]))
+	}
+	
+	#[test]
+	fn gcd() {
+		let a = p(vec![1, 3]) * p(vec![1, 5]) * p(vec![2, 1]);
+		let b = p(vec![1, 1]) * p(vec![1, 5]);
+		assert_eq!(a.gcd(&b), p(vec![1, 5]));
+		assert_eq!(a.gcd(&p(vec![1, 2])), p(vec![1]));
+		assert_eq!(a.gcd(&Polynomial::new(Vec::new())), a.gcd(&a));
+		assert_eq!(p(vec![0]).gcd(&p(vec![0])), Polynomial::new(Vec::new()))
+	}
+}
@@ -107,6 +107,13 @@ mod mult {
 			lhs1 *= lhs2;
 			assert_eq!(lhs1, rhs)
 		}
+		
+		#[test]
+		fn mult_zero() {
+			let zero = Polynomial::<i32>::new(Vec::new());
+			assert_eq!(zero.clone() * Polynomial::new(vec![1, 2]), zero);
+			assert_eq!(Polynomial::new(vec![0, 1, 1]) * Polynomial::new(vec![0, 2, -1]), Polynomial::new(vec![2, 1, -1]))
+		}
 	}
 	
 	#[cfg(test)]
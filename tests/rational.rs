@@ -0,0 +1,172 @@
+use lineas::{Complex, Matrix, Rational, Vector};
+use lineas::decompose::{LDLDecompose, LUDecompose};
+use lineas::polynomials::Polynomial;
+use lineas::scalar::{Field, Scalar};
+
+fn r(numerator: i64, denominator: i64) -> Rational<i64> {
+	Rational::new(numerator, denominator)
+}
+
+#[cfg(test)]
+mod construction {
+	use super::*;
+	
+	#[test]
+	fn reduced() {
+		assert_eq!(r(6, 8), r(3, 4));
+		assert_eq!((r(6, -8).numerator(), r(6, -8).denominator()), (-3, 4));
+		assert_eq!((r(-6, -8).numerator(), r(-6, -8).denominator()), (3, 4));
+		assert_eq!((r(0, -5).numerator(), r(0, -5).denominator()), (0, 1));
+		assert!(r(10, 5).is_integer())
+	}
+	
+	#[test]
+	#[should_panic]
+	fn zero_denominator() {
+		r(1, 0);
+	}
+	
+	#[test]
+	fn display() {
+		assert_eq!(r(-3, 4).to_string(), "-3/4");
+		assert_eq!(r(8, 4).to_string(), "2");
+		assert_eq!(format!("{:?}", r(1, 2)), "1/2");
+		assert_eq!(Polynomial::new(vec![r(1, 2), r(-1, 3)]).to_string(), "+1/2x-1/3")
+	}
+}
+
+#[cfg(test)]
+mod arithmetic {
+	use super::*;
+	
+	#[test]
+	fn ops() {
+		assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+		assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+		assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+		assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+		assert_eq!(-r(2, 3), r(-2, 3));
+		assert_eq!(r(-2, 3).recip(), r(-3, 2));
+		assert_eq!(r(4, 7).inverse(), r(7, 4));
+		let mut a = r(1, 4);
+		a += r(1, 4);
+		a *= r(4, 3);
+		a -= r(1, 3);
+		a /= r(1, 2);
+		assert_eq!(a, r(2, 3))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn divide_by_zero() {
+		let _ = r(1, 2) / r(0, 1);
+	}
+	
+	#[test]
+	fn ordering() {
+		assert!(r(1, 3) < r(1, 2));
+		assert!(r(-1, 2) < r(-1, 3));
+		assert_eq!(r(3, 4).max(r(5, 7)), r(3, 4));
+		assert!(r(i64::MAX - 1, i64::MAX) > r(i64::MAX - 2, i64::MAX - 1));
+		assert!(r(-i64::MAX, i64::MAX - 1) > r(1 - i64::MAX, i64::MAX - 2));
+		assert_eq!(r(i64::MIN + 1, 3).cmp(&r(i64::MIN + 1, 3)), std::cmp::Ordering::Equal)
+	}
+	
+	#[test]
+	fn conversions() {
+		assert_eq!(Matrix::new([[1, 2]]).dtype::<Rational<i64>>(), Matrix::new([[r(1, 1), r(2, 1)]]));
+		assert_eq!(Matrix::new([[r(1, 4)]]).dtype::<f64>(), Matrix::new([[0.25]]));
+		assert_eq!(Matrix::new([[300u32]]).try_dtype::<Rational<i8>>(), Err(lineas::Error::ConversionOverflow));
+		assert_eq!(Rational::<i32>::from_complex(Complex::from_real(0.75), 0.), Some(Rational::new(3, 4)));
+		assert_eq!(Rational::<i32>::from_complex(Complex::from_real(std::f64::consts::PI), 1e-6), Some(Rational::new(355, 113)));
+		assert_eq!(Rational::<i8>::from_complex(Complex::from_real(1000.5), 0.), None);
+		assert_eq!(r(9, 16).sqrt(), r(3, 4))
+	}
+}
+
+#[cfg(test)]
+mod exact {
+	use super::*;
+	
+	#[test]
+	fn determinant() {
+		let a = Matrix::new([[2, -3, 1, 4], [2, 0, -1, 1], [1, 4, 5, 0], [3, 1, 1, 2]]);
+		assert_eq!(a.dtype::<Rational<i64>>().determinant(), Rational::from_integer(a.determinant()));
+		let b = Matrix::new([[r(1, 2), r(1, 3)], [r(1, 4), r(1, 5)]]);
+		assert_eq!(b.determinant(), r(1, 60))
+	}
+	
+	#[test]
+	fn inverse() {
+		let a = Matrix::new([[1, 2, 3], [0, 1, 4], [5, 6, 0]]).dtype::<Rational<i64>>();
+		assert_eq!(a * a.inverse(), Matrix::identity());
+		let hilbert = Matrix::<4, 4, _>::new(std::array::from_fn(|i| std::array::from_fn(|j| r(1, (i + j + 1) as i64))));
+		assert_eq!(hilbert.inverse()[(3, 3)], r(2800, 1));
+		assert_eq!(Matrix::new([[r(1, 2), r(1, 4)], [r(2, 1), r(1, 1)]]).try_inverse(), Err(lineas::Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn lu() {
+		let a = Matrix::new([[2, 1], [3, 4]]).dtype::<Rational<i64>>();
+		let (l, u) = a.lu_decompose().unwrap();
+		assert_eq!(l * u, a);
+		assert_eq!(u[(0, 1)], r(1, 2))
+	}
+	
+	#[test]
+	fn ldl() {
+		let a = Matrix::new([[2, -1, 3], [-1, -4, 1], [3, 1, 0]]).dtype::<Rational<i64>>();
+		let (l, d) = a.ldl_decompose().unwrap();
+		assert_eq!(l * d * l.transpose(), a);
+		assert_eq!(d[(1, 1)], r(-9, 2))
+	}
+	
+	#[test]
+	fn rref() {
+		let a = Matrix::new([[2, 1, 1], [4, 3, 3]]).dtype::<Rational<i64>>();
+		assert_eq!(a.rref(), Matrix::new([[1, 0, 0], [0, 1, 1]]).dtype());
+		assert_eq!(a.rank(), 2)
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Complex::from_complex(r(1, 2), r(1, 3));
+		let b = Complex::from_complex(r(2, 1), r(-1, 1));
+		assert_eq!(a * b / b, a);
+		assert_eq!(a.conj() + a, Complex::from_real(r(1, 1)))
+	}
+	
+	#[test]
+	fn vectors() {
+		let a = Vector::new([[r(3, 1), r(-4, 1)]]);
+		assert_eq!(a.normalize(), Some(Vector::new([[r(3, 5), r(-4, 5)]])));
+		assert_eq!(Vector::new([[r(1, 1), r(1, 1)]]).normalize(), None);
+		assert_eq!(a.abs(), Vector::new([[r(3, 1), r(4, 1)]]));
+		assert_eq!(Vector::new([[r(-1, 3)]]).abs(), Vector::new([[r(1, 3)]]))
+	}
+	
+	#[test]
+	fn polynomial_division() {
+		let a = Polynomial::new(vec![1, 0, -2, 5]).dtype::<Rational<i64>>();
+		let b = Polynomial::new(vec![2, 1]).dtype::<Rational<i64>>();
+		let (quotient, remainder) = a.div_rem(&b);
+		assert_eq!(quotient, Polynomial::new(vec![r(1, 2), r(-1, 4), r(-7, 8)]));
+		assert_eq!(remainder, Polynomial::new(vec![r(47, 8)]));
+		assert_eq!(quotient * b.clone() + remainder, a.clone());
+		assert_eq!(a.clone() / b.clone(), a.div_rem(&b).0);
+		assert_eq!(b.clone() % a.clone(), b)
+	}
+	
+	#[test]
+	fn polynomial_division_degenerate() {
+		let a = Polynomial::new(vec![1, 0, -2, 5]).dtype::<Rational<i64>>();
+		let b = Polynomial::new(vec![2, 1]).dtype::<Rational<i64>>();
+		let (quotient, remainder) = b.div_rem(&a);
+		assert_eq!(quotient.degree(), 0);
+		assert_eq!(quotient * a.clone() + remainder, b.clone());
+		let product = a.clone() * b.clone();
+		let (quotient, remainder) = product.div_rem(&b);
+		assert_eq!((quotient.clone(), remainder.degree()), (a, 0));
+		assert_eq!(quotient * b + remainder, product)
+	}
+}
@@ -0,0 +1,77 @@
+use lineas::{Complex, Matrix};
+use lineas::polynomials::Polynomial;
+use lineas::scalar::{ComplexField, Field, One, RealField, Ring, Zero};
+
+#[cfg(test)]
+mod identities {
+	use super::*;
+	
+	#[test]
+	fn primitives() {
+		assert_eq!(i8::zero(), 0);
+		assert_eq!(isize::one(), 1);
+		assert_eq!(f32::zero(), 0.);
+		assert_eq!(f64::one(), 1.);
+		assert_eq!(i32::from_usize(12), 12);
+		assert_eq!(f64::from_usize(7), 7.)
+	}
+	
+	#[test]
+	fn complex() {
+		assert_eq!(Complex::<i32>::zero(), Complex::from_complex(0, 0));
+		assert_eq!(Complex::<f64>::one(), Complex::from_complex(1., 0.));
+		assert_eq!(Complex::<i64>::from_usize(9), Complex::from_complex(9, 0))
+	}
+	
+	#[derive(Copy, Clone, Debug, PartialEq)]
+	struct Mod7(u8);
+	
+	impl std::ops::Add for Mod7 { type Output = Self; fn add(self, rhs: Self) -> Self { Mod7((self.0 + rhs.0) % 7) } }
+	impl std::ops::Sub for Mod7 { type Output = Self; fn sub(self, rhs: Self) -> Self { Mod7((self.0 + 7 - rhs.0) % 7) } }
+	impl std::ops::Mul for Mod7 { type Output = Self; fn mul(self, rhs: Self) -> Self { Mod7((self.0 * rhs.0) % 7) } }
+	impl std::ops::Neg for Mod7 { type Output = Self; fn neg(self) -> Self { Mod7((7 - self.0) % 7) } }
+	impl std::ops::AddAssign for Mod7 { fn add_assign(&mut self, rhs: Self) { *self = *self + rhs } }
+	impl std::ops::SubAssign for Mod7 { fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs } }
+	impl std::ops::MulAssign for Mod7 { fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs } }
+	impl Zero for Mod7 { fn zero() -> Self { Mod7(0) } }
+	impl One for Mod7 { fn one() -> Self { Mod7(1) } }
+	impl Ring for Mod7 {}
+	
+	#[test]
+	fn custom() {
+		assert_eq!(Mod7::from_usize(12), Mod7(5));
+		assert_eq!(Matrix::<2, 2, Mod7>::identity(), Matrix::new([[Mod7(1), Mod7(0)], [Mod7(0), Mod7(1)]]));
+		let a = Matrix::new([[Mod7(3), Mod7(4)], [Mod7(1), Mod7(6)]]);
+		assert_eq!(a * Matrix::identity(), a);
+		assert_eq!(a.trace(), Mod7(2));
+		assert_eq!(Polynomial::new(vec![Mod7(3), Mod7(4)]) * Mod7(2), Polynomial::new(vec![Mod7(6), Mod7(1)]))
+	}
+}
+
+#[cfg(test)]
+mod fields {
+	use super::*;
+	
+	#[test]
+	fn inverse() {
+		assert_eq!(4f64.inverse(), 0.25);
+		assert_eq!(Complex::from_complex(0f64, 2.).inverse(), Complex::from_complex(0., -0.5))
+	}
+	
+	#[test]
+	fn real() {
+		assert_eq!(RealField::abs(&-3f32), 3.);
+		assert_eq!(2.5f32.to_f64(), 2.5);
+		assert_eq!(f32::from_f64(0.5), 0.5)
+	}
+	
+	#[test]
+	fn complex() {
+		let z = Complex::<f64>::from_parts(3., -4.);
+		assert_eq!((z.real_part(), z.imaginary_part()), (3., -4.));
+		assert_eq!(z.magnitude(), 5.);
+		assert!((Complex::from_complex(3e200, 4e200).magnitude() / 5e200 - 1.).abs() < 1e-15);
+		assert_eq!((-2f64).magnitude(), 2.);
+		assert_eq!(f64::from_parts(1., 5.).imaginary_part(), 0.)
+	}
+}
@@ -0,0 +1,128 @@
+use lineas::{Complex, Error, Matrix, Vector};
+
+#[cfg(test)]
+mod rref {
+	use super::*;
+	
+	#[test]
+	fn float() {
+		let a = Matrix::new([[0, 2, 4, 2], [1, 1, 1, 1], [2, 4, 6, 4]]).dtype::<f64>();
+		assert_eq!(a.rref(), Matrix::new([[1., 0., -1., 0.], [0., 1., 2., 1.], [0., 0., 0., 0.]]))
+	}
+	
+	#[test]
+	fn integer() {
+		let a = Matrix::new([[2, 3, 5], [4, 1, 2]]);
+		// The true reduced row echelon form is [[1, 0, 1/10], [0, 1, 8/5]]
+		assert_eq!(a.rref(), Matrix::new([[10, 0, 1], [0, 5, 8]]))
+	}
+	
+	#[test]
+	fn overflow() {
+		let a = Matrix::new([[7i8, 11, 13], [17, 19, 23], [29, 31, 37]]);
+		assert_eq!(a.try_rref(), Err(Error::Overflow));
+		assert_eq!(a.dtype::<i32>().try_rref(), Ok(Matrix::identity()));
+		let b = Matrix::new([[2i8, 3, 5, 7], [11, 13, 17, 19], [23, 29, 31, 37]]);
+		assert_eq!(b.dtype::<i64>().rref(), Matrix::new([[7, 0, 0, -16], [0, 7, 0, 12], [0, 0, 7, 9]]))
+	}
+	
+	#[test]
+	fn zero() {
+		assert_eq!(Matrix::<2, 3, f64>::empty().rref(), Matrix::empty());
+		assert_eq!(Matrix::<2, 3, i32>::empty().rref(), Matrix::empty())
+	}
+	
+	#[test]
+	fn tolerance() {
+		let a = Matrix::new([[1., 1.], [1., 1. + 1e-10]]);
+		assert_eq!(a.rref(), Matrix::identity());
+		assert_eq!(a.rref_with_tolerance(1e-8), Matrix::new([[1., 1.], [0., 0.]]))
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_real(1.), Complex::from_imaginary(1.)],
+			[Complex::from_imaginary(1.), Complex::from_real(-1.)]
+		]);
+		assert_eq!(a.rref(), Matrix::new([
+			[Complex::from_real(1.), Complex::from_imaginary(1.)],
+			[Complex::from_real(0.), Complex::from_real(0.)]
+		]))
+	}
+}
+
+#[cfg(test)]
+mod rank {
+	use super::*;
+	
+	#[test]
+	fn full() {
+		assert_eq!(Matrix::<4, 4, f64>::identity().rank(), 4);
+		assert_eq!(Matrix::new([[1, 2, 3], [4, 5, 6]]).rank(), 2);
+		assert_eq!(Matrix::new([[1, 2], [3, 4], [5, 7]]).dtype::<f64>().rank(), 2)
+	}
+	
+	#[test]
+	fn deficient() {
+		assert_eq!(Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).rank(), 2);
+		assert_eq!(Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).dtype::<f64>().rank(), 2);
+		assert_eq!(Matrix::new([[1, -2], [-3, 6]]).rank(), 1);
+		assert_eq!(Matrix::<3, 2, f64>::empty().rank(), 0)
+	}
+	
+	#[test]
+	fn rank_nullity() {
+		let a = Matrix::new([[0, 2, 4, 2], [1, 1, 1, 1], [2, 4, 6, 4]]);
+		assert_eq!(a.rank() + a.null_space().len(), 4);
+		assert_eq!(a.rank() + a.left_null_space().len(), 3);
+		assert_eq!(a.rank(), a.column_space().len());
+		assert_eq!(a.rank(), a.row_space().len())
+	}
+}
+
+#[cfg(test)]
+mod spaces {
+	use super::*;
+	
+	#[test]
+	fn null_space_float() {
+		let a = Matrix::new([[0, 2, 4, 2], [1, 1, 1, 1], [2, 4, 6, 4]]).dtype::<f64>();
+		let basis = a.null_space();
+		assert_eq!(basis, vec![Vector::new([[1., -2., 1., 0.]]), Vector::new([[0., -1., 0., 1.]])]);
+		for x in basis {
+			assert_eq!(a * x.transpose(), Matrix::empty())
+		}
+	}
+	
+	#[test]
+	fn null_space_integer() {
+		let a = Matrix::new([[2, 3, 5], [4, 1, 2]]);
+		let basis = a.null_space();
+		assert_eq!(basis, vec![Vector::new([[-1, -16, 10]])]);
+		assert_eq!(a * basis[0].transpose(), Matrix::empty())
+	}
+	
+	#[test]
+	fn full_rank() {
+		let a = Matrix::new([[2, 1], [1, 3]]);
+		assert!(a.null_space().is_empty());
+		assert!(a.left_null_space().is_empty());
+		assert_eq!(a.column_space(), vec![Vector::new([[2, 1]]), Vector::new([[1, 3]])]);
+		assert_eq!(a.row_space(), vec![Vector::new([[1, 0]]), Vector::new([[0, 1]])])
+	}
+	
+	#[test]
+	fn left_null_space() {
+		let a = Matrix::new([[1, 2], [2, 4], [0, 1]]).dtype::<f64>();
+		let basis = a.left_null_space();
+		assert_eq!(basis.len(), 1);
+		assert_eq!(basis[0] * a, Matrix::empty())
+	}
+	
+	#[test]
+	fn column_space() {
+		let a = Matrix::new([[1, 2, 0], [2, 4, 1], [3, 6, 1]]);
+		assert_eq!(a.column_space(), vec![Vector::new([[1, 2, 3]]), Vector::new([[0, 1, 1]])])
+	}
+}
@@ -1,4 +1,5 @@
-use lineas::Matrix;
+use lineas::{Complex, Error, Matrix};
+use lineas::scalar::Scalar;
 
 #[cfg(test)]
 mod det {
@@ -26,11 +27,211 @@ mod det {
 		]).dtype::<f32>();
 		assert_eq!(a.determinant(), -4111f32)
 	}
+	
+	#[test]
+	fn integer_exact() {
+		let a = Matrix::new([
+			[2, 0, 1, 3, -1, 4],
+			[0, 0, 5, -2, 1, 1],
+			[1, 3, 0, 0, 2, -3],
+			[-4, 1, 2, 1, 0, 2],
+			[3, -1, 1, 2, 2, 0],
+			[1, 2, -1, 0, 3, 1]
+		]);
+		assert_eq!(a.determinant(), -4920);
+		assert!((a.dtype::<f64>().determinant() + 4920f64).abs() < 1e-9)
+	}
+	
+	#[test]
+	fn zero_pivot() {
+		let a = Matrix::new([
+			[0, 1, 0, 0],
+			[1, 0, 0, 0],
+			[0, 0, 0, 1],
+			[0, 0, 1, 0]
+		]);
+		assert_eq!(a.determinant(), 1);
+		assert_eq!(a.dtype::<f64>().determinant(), 1f64)
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([
+			[1, 2, 3, 4],
+			[2, 4, 6, 8],
+			[0, 1, 5, 2],
+			[3, 1, 0, 7]
+		]);
+		assert_eq!(a.determinant(), 0);
+		assert_eq!(a.dtype::<f64>().determinant(), 0f64)
+	}
+	
+	#[test]
+	fn large() {
+		let mut a: Matrix<12, 12, f64> = Matrix::identity();
+		for i in 0..12 {
+			a[(i, i)] = (i + 1) as f64;
+			a[(0, i)] = 1.;
+		}
+		assert!((a.determinant() - 479001600f64).abs() < 1e-3)
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_complex(1, 1), Complex::from_real(2), Complex::from_real(0), Complex::from_imaginary(1)],
+			[Complex::from_real(0), Complex::from_real(1), Complex::from_imaginary(-1), Complex::from_real(0)],
+			[Complex::from_real(3), Complex::from_real(0), Complex::from_real(1), Complex::from_real(0)],
+			[Complex::from_real(0), Complex::from_real(0), Complex::from_real(0), Complex::from_real(2)]
+		]);
+		assert_eq!(a.determinant(), Complex::from_complex(2, -10));
+		let mut b = Matrix::<4, 4, Complex<f64>>::empty();
+		for r in 0..4 {
+			for c in 0..4 {
+				b[(r, c)] = a[(r, c)].dtype();
+			}
+		}
+		assert!((b.determinant() - Complex::from_complex(2., -10.)).modulus() < 1e-12)
+	}
+}
+
+#[cfg(test)]
+mod inverse {
+	use super::*;
+	
+	#[test]
+	fn float() {
+		let a = Matrix::new([
+			[0.3f64, -1.7, 2.2, 9.1],
+			[4.4, 0.0, -3.3, 1.0],
+			[-6.1, 2.8, 0.9, -0.4],
+			[1.2, 5.5, -2.0, 3.3]
+		]);
+		let check = a * a.inverse() - Matrix::identity();
+		for r in 0..4 {
+			for c in 0..4 {
+				assert!(check[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn pivoting() {
+		let a = Matrix::new([[0, 1, 2], [1, 0, 3], [4, -3, 8]]).dtype::<f64>();
+		let inverse = Matrix::new([[-4.5, 7., -1.5], [-2., 4., -1.], [1.5, -2., 0.5]]);
+		let check = a.inverse() - inverse;
+		for r in 0..3 {
+			for c in 0..3 {
+				assert!(check[(r, c)].abs() < 1e-14)
+			}
+		}
+	}
+	
+	#[test]
+	fn singular() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).dtype::<f64>();
+		assert_eq!(a.try_inverse(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn near_singular() {
+		let a = Matrix::new([[1., 2.], [1., 2. + 1e-10]]);
+		assert!(a.try_inverse().is_ok());
+		assert_eq!(a.try_inverse_with_tolerance(1e-8), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	#[should_panic]
+	fn singular_panic() {
+		Matrix::new([[1., 2.], [2., 4.]]).inverse();
+	}
+	
+	#[test]
+	fn integer() {
+		let a = Matrix::new([[0, 1, 2], [1, 0, 3], [0, 0, 1]]);
+		let inverse = a.try_inverse().unwrap();
+		assert_eq!(inverse, Matrix::new([[0, 1, -3], [1, 0, -2], [0, 0, 1]]));
+		assert_eq!(a * inverse, Matrix::identity())
+	}
+	
+	#[test]
+	fn integer_singular() {
+		let a = Matrix::new([[2, 3, 1], [1, 2, 1], [0, 1, 1]]);
+		assert_eq!(a.try_inverse(), Err(Error::SingularMatrix))
+	}
+	
+	#[test]
+	fn integer_non_integer_inverse() {
+		let a = Matrix::new([[2, 0], [0, 1]]);
+		assert_eq!(a.try_inverse(), Err(Error::InexactDivision));
+		assert_eq!(a.dtype::<f64>().try_inverse(), Ok(Matrix::new([[0.5, 0.], [0., 1.]])))
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_real(2.)],
+			[Complex::from_imaginary(-1.), Complex::from_real(1.)]
+		]);
+		let check = a * a.inverse() - Matrix::identity();
+		for r in 0..2 {
+			for c in 0..2 {
+				assert!(check[(r, c)].modulus() < 1e-14)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod characteristic {
+	use super::*;
+	use lineas::polynomials::Polynomial;
+	
+	#[test]
+	fn integer() {
+		let a = Matrix::new([[2, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+		assert_eq!(a.characteristic_polynomial(), Polynomial::new(vec![1, -6, 10, -4]))
+	}
+	
+	#[test]
+	fn dtypes() {
+		let a = Matrix::new([[3, 1, 0, 2], [1, 0, -2, 1], [4, 1, 1, 0], [0, 2, 1, -1]]);
+		assert_eq!(a.characteristic_polynomial(), Polynomial::new(vec![1, -3, -2, 2, 30]));
+		assert_eq!(a.dtype::<f64>().characteristic_polynomial(), Polynomial::new(vec![1., -3., -2., 2., 30.]));
+		assert_eq!(a.determinant(), 30)
+	}
+	
+	#[test]
+	fn roots() {
+		let a = Matrix::new([[4, 1], [2, 3]]);
+		assert_eq!(a.characteristic_polynomial().real_roots(), Ok(vec![5f32, 2f32]))
+	}
+	
+	#[test]
+	fn trace() {
+		let a = Matrix::new([[5, 9, 1], [-2, 3, 0], [7, 7, -4]]);
+		assert_eq!(a.trace(), 4);
+		assert_eq!(a.characteristic_polynomial().degree(), 3)
+	}
+	
+	#[test]
+	fn adjugate() {
+		let a = Matrix::new([[2, -3, 1], [2, 0, -1], [1, 4, 5]]);
+		let check = Matrix::new([[4, 19, 3], [-11, 9, 4], [8, -11, 6]]);
+		assert_eq!(a.adjugate(), check);
+		assert_eq!(a * a.adjugate(), Matrix::identity().scale(a.determinant()))
+	}
+	
+	#[test]
+	fn adjugate_singular() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+		assert_eq!(a.adjugate(), Matrix::new([[-3, 6, -3], [6, -12, 6], [-3, 6, -3]]));
+		assert_eq!(a * a.adjugate(), Matrix::empty())
+	}
 }
 
 #[cfg(test)]
 mod conjugate {
-	use lineas::Complex;
 	use super::*;
 	
 	#[cfg(test)]
@@ -85,3 +286,268 @@ mod conjugate {
 		}
 	}
 }
+
+#[cfg(test)]
+mod eigenvalues {
+	use super::*;
+	
+	fn assert_values<const T: usize>(a: Matrix<T, T, f64>, expected: &[Complex<f64>]) {
+		let values = a.eigenvalues().unwrap();
+		assert_eq!(values.len(), expected.len());
+		for (value, expected) in values.iter().zip(expected.iter()) {
+			assert!((*value - *expected).modulus() < 1e-10, "{} != {}", value, expected)
+		}
+	}
+	
+	/// Check `Av = λv` and `|v| = 1` for every pair
+	fn assert_vectors<const T: usize>(a: Matrix<T, T, f64>, count: usize) {
+		let pairs = a.eigenvectors().unwrap();
+		assert_eq!(pairs.len(), count);
+		for (value, vector) in pairs {
+			let length = (0..T).map(|i| vector[(0, i)].modulus().powi(2)).sum::<f64>();
+			assert!((length - 1.).abs() < 1e-12);
+			for i in 0..T {
+				let product = (0..T).fold(Complex::from_real(0.), |s, j| s + Complex::from_real(a[(i, j)]) * vector[(0, j)]);
+				assert!((product - value * vector[(0, i)]).modulus() < 1e-10)
+			}
+		}
+	}
+	
+	#[test]
+	fn real() {
+		let a = Matrix::new([[2, 0, 0], [0, 3, 4], [0, 4, 9]]).dtype::<f64>();
+		assert_values(a, &[Complex::from_real(1.), Complex::from_real(2.), Complex::from_real(11.)]);
+		assert_vectors(a, 3)
+	}
+	
+	#[test]
+	fn complex_pair() {
+		let a = Matrix::new([[0, -1], [1, 0]]).dtype::<f64>();
+		assert_values(a, &[Complex::from_imaginary(-1.), Complex::from_imaginary(1.)]);
+		assert_vectors(a, 2)
+	}
+	
+	#[test]
+	fn mixed() {
+		let a = Matrix::new([[1, -2, 0, 0], [2, 1, 0, 0], [0, 0, 3, 1], [0, 0, 0, -1]]).dtype::<f64>();
+		assert_values(a, &[
+			Complex::from_real(-1.), Complex::from_complex(1., -2.), Complex::from_complex(1., 2.), Complex::from_real(3.)
+		]);
+		assert_vectors(a, 4)
+	}
+	
+	#[test]
+	fn companion() {
+		// (x-1)(x-2)(x-3)(x-4)(x-5) = x⁵ - 15x⁴ + 85x³ - 225x² + 274x - 120
+		let a = Matrix::new([
+			[15, -85, 225, -274, 120],
+			[1, 0, 0, 0, 0],
+			[0, 1, 0, 0, 0],
+			[0, 0, 1, 0, 0],
+			[0, 0, 0, 1, 0]
+		]).dtype::<f64>();
+		assert_values(a, &(1..=5).map(|v| Complex::from_real(v as f64)).collect::<Vec<_>>());
+		assert_vectors(a, 5)
+	}
+	
+	#[test]
+	fn dense() {
+		let a = Matrix::new([
+			[4, -2, 7, 1, 0, 3],
+			[-1, 5, 2, -6, 8, 0],
+			[3, 3, -4, 2, 1, -7],
+			[0, 9, 1, 1, -2, 4],
+			[6, -1, 0, 5, 3, 2],
+			[2, 2, 2, -3, -5, 1]
+		]).dtype::<f64>();
+		let values = a.eigenvalues().unwrap();
+		let sum = values.iter().fold(Complex::from_real(0.), |s, v| s + *v);
+		let product = values.iter().fold(Complex::from_real(1.), |s, v| s * *v);
+		assert!((sum - Complex::from_real(a.trace())).modulus() < 1e-10);
+		assert!((product - Complex::from_real(a.determinant())).modulus() < 1e-8 * a.determinant().abs());
+		assert_vectors(a, 6)
+	}
+	
+	#[test]
+	fn repeated() {
+		let a = Matrix::<3, 3, f64>::identity();
+		assert_values(a, &[Complex::from_real(1.); 3]);
+		assert_vectors(a, 3)
+	}
+	
+	#[test]
+	fn defective() {
+		let a = Matrix::new([[1, 1], [0, 1]]).dtype::<f64>();
+		assert_values(a, &[Complex::from_real(1.); 2]);
+		assert_vectors(a, 1)
+	}
+}
+
+#[cfg(test)]
+mod norms {
+	use super::*;
+	use lineas::decompose::PLUFactorisation;
+	
+	fn hilbert<const T: usize>() -> Matrix<T, T, f64> {
+		let mut out = Matrix::empty();
+		for i in 0..T {
+			for j in 0..T {
+				out[(i, j)] = 1. / (i + j + 1) as f64
+			}
+		}
+		out
+	}
+	
+	#[test]
+	fn elementwise() {
+		let a = Matrix::new([[1, -2, 3], [-4, 5, -6]]);
+		assert_eq!(a.max_abs(), 6.);
+		assert_eq!(a.norm_1(), 9.);
+		assert_eq!(a.norm_inf(), 15.);
+		assert_eq!(a.norm_frobenius(), 91f64.sqrt())
+	}
+	
+	#[test]
+	fn complex() {
+		let a = Matrix::new([
+			[Complex::from_complex(3., 4.), Complex::from_real(0.)],
+			[Complex::from_imaginary(-1.), Complex::from_complex(0., 0.)]
+		]);
+		assert_eq!(a.max_abs(), 5.);
+		assert_eq!(a.norm_1(), 6.);
+		assert_eq!(a.norm_inf(), 5.);
+		assert!((a.norm_2().unwrap() - 26f64.sqrt()).abs() < 1e-14);
+		assert!((a.norm_frobenius() - 26f64.sqrt()).abs() < 1e-14)
+	}
+	
+	#[test]
+	fn singular_value_norms() {
+		let a = Matrix::new([[1, -2, 3], [-4, 5, -6]]).dtype::<f64>();
+		let (largest, nuclear) = (a.norm_2().unwrap(), a.norm_nuclear().unwrap());
+		assert!(largest <= a.norm_frobenius() && a.norm_frobenius() <= nuclear);
+		assert!((largest.powi(2) - a.transpose().norm_2().unwrap().powi(2)).abs() < 1e-12);
+		// The squared singular values are the eigenvalues of AAᵀ, [[14, -32], [-32, 77]]
+		let root = (91f64.powi(2) - 4. * (14. * 77. - 32. * 32.)).sqrt();
+		assert!((largest - ((91. + root) / 2.).sqrt()).abs() < 1e-12);
+		assert!((nuclear - ((91. + root) / 2.).sqrt() - ((91. - root) / 2.).sqrt()).abs() < 1e-12)
+	}
+	
+	#[test]
+	fn condition_number() {
+		assert_eq!(Matrix::<3, 3, f64>::identity().condition_number(), Ok(1.));
+		assert!((hilbert::<4>().condition_number().unwrap() / 15513.738738929 - 1.).abs() < 1e-8);
+		assert_eq!(Matrix::<2, 2, f64>::empty().condition_number(), Ok(f64::INFINITY))
+	}
+	
+	#[test]
+	fn solve_transpose() {
+		let a = Matrix::new([[0, 2, 1], [3, -1, 2], [1, 1, 4]]).dtype::<f64>();
+		let b = Matrix::new([[1., 0.], [2., -1.], [3., 5.]]);
+		let x = PLUFactorisation::new(&a).unwrap().solve_transpose(&b);
+		let check = a.transpose() * x - b;
+		assert!((0..3).all(|i| (0..2).all(|j| check[(i, j)].abs() < 1e-14)));
+		let c = Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_real(2.)],
+			[Complex::from_imaginary(2.), Complex::from_complex(0., -1.)]
+		]);
+		let d = Matrix::new([[Complex::from_real(1.)], [Complex::from_imaginary(1.)]]);
+		let y = PLUFactorisation::new(&c).unwrap().solve_transpose(&d);
+		for i in 0..2 {
+			let value = (0..2).fold(Complex::from_real(0.), |s, k| s + c[(k, i)].conj() * y[(k, 0)]);
+			assert!((value - d[(i, 0)]).modulus() < 1e-14)
+		}
+	}
+	
+	/// Check the estimate is no larger than the exact 1-norm condition number and within a
+	/// factor of three of it
+	fn assert_estimate<const T: usize, L: Scalar>(a: Matrix<T, T, L>) {
+		let exact = a.norm_1() * a.inverse().norm_1();
+		let estimate = PLUFactorisation::new(&a).unwrap().condition_estimate();
+		assert!(estimate <= exact * (1. + 1e-10) && 3. * estimate >= exact, "{} {}", estimate, exact)
+	}
+	
+	#[test]
+	fn condition_estimate() {
+		assert_estimate(Matrix::<3, 3, f64>::identity());
+		assert_estimate(hilbert::<5>());
+		assert_estimate(Matrix::new([
+			[4, -2, 7, 1, 0, 3],
+			[-1, 5, 2, -6, 8, 0],
+			[3, 3, -4, 2, 1, -7],
+			[0, 9, 1, 1, -2, 4],
+			[6, -1, 0, 5, 3, 2],
+			[2, 2, 2, -3, -5, 1]
+		]).dtype::<f64>());
+		assert_estimate(Matrix::new([
+			[Complex::from_complex(1., 1.), Complex::from_real(2.), Complex::from_imaginary(-3.)],
+			[Complex::from_imaginary(2.), Complex::from_complex(0., -1.), Complex::from_real(1.)],
+			[Complex::from_real(-1.), Complex::from_complex(4., 2.), Complex::from_complex(1., 1.)]
+		]))
+	}
+}
+
+#[cfg(test)]
+mod products {
+	use super::*;
+	
+	#[test]
+	fn kronecker() {
+		let a = Matrix::new([[1, 2, 3]]);
+		let b = Matrix::new([[1], [-1]]);
+		let product: Matrix<2, 3, _> = a.kronecker(&b);
+		assert_eq!(product, Matrix::new([[1, 2, 3], [-1, -2, -3]]));
+		let product: Matrix<2, 3, _> = b.kronecker(&a);
+		assert_eq!(product, Matrix::new([[1, 2, 3], [-1, -2, -3]]));
+		let identity: Matrix<4, 4, i32> = Matrix::<2, 2, i32>::identity().kronecker(&Matrix::<2, 2, i32>::identity());
+		assert_eq!(identity, Matrix::identity())
+	}
+	
+	#[test]
+	fn mixed_product() {
+		let a = Matrix::new([[1, 2], [0, -1]]);
+		let b = Matrix::new([[3, 1, 0], [2, 2, 1]]);
+		let c = Matrix::new([[2, 0], [1, 1]]);
+		let d = Matrix::new([[1, 0], [0, 1], [4, -2]]);
+		let left: Matrix<4, 6, _> = a.kronecker(&b);
+		let right: Matrix<6, 4, _> = c.kronecker(&d);
+		let product: Matrix<4, 4, _> = (a * c).kronecker(&(b * d));
+		assert_eq!(left * right, product)
+	}
+	
+	#[test]
+	fn kronecker_determinant() {
+		let a = Matrix::new([[2i64, 1], [1, 3]]);
+		let b = Matrix::new([[1i64, 4, 0], [0, 2, 1], [1, 0, 1]]);
+		let product: Matrix<6, 6, _> = a.kronecker(&b);
+		assert_eq!(product.determinant(), a.determinant().pow(3) * b.determinant().pow(2))
+	}
+	
+	#[test]
+	fn hadamard() {
+		let a = Matrix::new([[1., -2., 3.], [0.5, 4., -1.]]);
+		let b = Matrix::new([[2., 2., -1.], [4., 0.25, 3.]]);
+		let product = a.hadamard(&b);
+		assert_eq!(product, Matrix::new([[2., -4., -3.], [2., 1., -3.]]));
+		assert_eq!(product.hadamard_div(&b), a);
+		assert_eq!(a.hadamard(&b), b.hadamard(&a))
+	}
+	
+	#[test]
+	fn complex_hadamard() {
+		let a = Matrix::new([[Complex::from_complex(1., 1.), Complex::from_imaginary(2.)]]);
+		let b = Matrix::new([[Complex::from_complex(1., -1.), Complex::from_imaginary(2.)]]);
+		assert_eq!(a.hadamard(&b), Matrix::new([[Complex::from_real(2.), Complex::from_real(-4.)]]));
+		assert_eq!(a.hadamard_div(&a), Matrix::new([[Complex::from_real(1.), Complex::from_real(1.)]]))
+	}
+	
+	#[test]
+	fn direct_sum() {
+		let a = Matrix::new([[1, 2], [3, 4]]);
+		let b = Matrix::new([[5]]);
+		let sum: Matrix<3, 3, _> = a.direct_sum(&b);
+		assert_eq!(sum, Matrix::new([[1, 2, 0], [3, 4, 0], [0, 0, 5]]));
+		assert_eq!(sum.determinant(), a.determinant() * b.determinant());
+		let sum: Matrix<3, 3, _> = b.direct_sum(&a);
+		assert_eq!(sum, Matrix::new([[5, 0, 0], [0, 1, 2], [0, 3, 4]]))
+	}
+}
@@ -0,0 +1,190 @@
+use lineas::{ColVector, Complex, Matrix, RowVector, Vector};
+use lineas::scalar::Scalar;
+
+#[cfg(test)]
+mod products {
+	use super::*;
+	
+	#[test]
+	fn dot() {
+		let a = Vector::new([[1., -2., 0.5]]);
+		assert_eq!(a.dot(&a), a.norm().powi(2));
+		assert_eq!(a.dot(&Vector::new([[2., 1., 7.]])), 3.5)
+	}
+	
+	#[test]
+	fn complex_dot() {
+		let a = Vector::new([[Complex::from_complex(1, 2), Complex::from_imaginary(-1)]]);
+		let b = Vector::new([[Complex::from_real(3), Complex::from_complex(2, 1)]]);
+		// (1 - 2i)3 + (i)(2 + i) = 3 - 6i + 2i - 1
+		assert_eq!(a.dot(&b), Complex::from_complex(2, -4));
+		assert_eq!(b.dot(&a), a.dot(&b).conj());
+		assert_eq!(a.dot(&a), Complex::from_real(6))
+	}
+	
+	#[test]
+	fn cross() {
+		let a = Vector::new([[1, 2, 3]]);
+		let b = Vector::new([[-4, 0, 5]]);
+		let c = a.cross(&b);
+		assert_eq!(c, Vector::new([[10, -17, 8]]));
+		assert_eq!(b.cross(&a), -c);
+		assert_eq!(c.dot(&a), 0);
+		assert_eq!(c.dot(&b), 0);
+		assert_eq!(a.cross(&a), Vector::empty())
+	}
+	
+	#[test]
+	fn outer() {
+		let a = Vector::new([[Complex::from_complex(1, 1), Complex::from_real(2)]]);
+		let m = a.outer(&a);
+		assert_eq!(m, Matrix::new([
+			[Complex::from_real(2), Complex::from_complex(2, 2)],
+			[Complex::from_complex(2, -2), Complex::from_real(4)]
+		]));
+		assert_eq!(Vector::new([[1, 2, 3]]).outer(&Vector::new([[1]])), Vector::new([[1, 2, 3]]).transpose())
+	}
+}
+
+#[cfg(test)]
+mod geometry {
+	use super::*;
+	use std::f64::consts::{FRAC_PI_2, PI};
+	
+	#[test]
+	fn norm() {
+		let a = Vector::new([[1, -2, 2]]);
+		assert_eq!(a.norm(), 3.);
+		assert_eq!(a.norm_p(1.), 5.);
+		assert_eq!(a.norm_p(2.), 3.);
+		assert_eq!(a.norm_p(f64::INFINITY), 2.);
+		assert_eq!(Vector::new([[Complex::from_complex(3., 4.)]]).norm(), 5.)
+	}
+	
+	#[test]
+	fn normalize() {
+		assert_eq!(Vector::new([[0., -2., 0.]]).normalize(), Some(Vector::new([[0., -1., 0.]])));
+		assert_eq!(Vector::new([[3f32, 4.]]).normalize(), Some(Vector::new([[0.6, 0.8]])));
+		assert_eq!(Vector::new([[0, 1]]).normalize(), Some(Vector::new([[0, 1]])));
+		assert_eq!(Vector::new([[1, 1]]).normalize(), None);
+		let unit = Vector::new([[Complex::from_complex(1., 1.), Complex::from_complex(1., -1.)]]).normalize().unwrap();
+		assert!((unit.norm() - 1.).abs() < 1e-15)
+	}
+	
+	#[test]
+	fn angle() {
+		let a = Vector::new([[1., 2., 0.]]);
+		assert_eq!(a.angle_between(&a), 0.);
+		assert_eq!(a.angle_between(&a.scale(-3.)), PI);
+		assert!((a.angle_between(&Vector::new([[-2., 1., 5.]])) - FRAC_PI_2).abs() < 1e-15);
+		assert!(a.angle_between(&Vector::empty()).is_nan())
+	}
+	
+	#[test]
+	fn projection() {
+		let a = Vector::new([[3., 1., -2.]]);
+		let b = Vector::new([[1., 1., 0.]]);
+		let projection = a.project_onto(&b);
+		let rejection = a.reject_from(&b);
+		assert_eq!(projection, Vector::new([[2., 2., 0.]]));
+		assert_eq!(rejection, Vector::new([[1., -1., -2.]]));
+		assert_eq!(projection + rejection, a);
+		assert_eq!(rejection.dot(&b), 0.)
+	}
+	
+	#[test]
+	fn complex_projection() {
+		let a = Vector::new([[Complex::from_real(1.), Complex::from_imaginary(2.)]]);
+		let b = Vector::new([[Complex::from_imaginary(1.), Complex::from_real(1.)]]);
+		let rejection = a.reject_from(&b);
+		assert!(b.dot(&rejection).modulus() < 1e-15);
+		assert!((a.project_onto(&b) + rejection - a).max_abs() < 1e-15)
+	}
+}
+
+#[cfg(test)]
+mod elementwise {
+	use super::*;
+	
+	#[test]
+	fn abs() {
+		assert_eq!(Matrix::new([[-1.5, 2.], [0., -3.]]).abs(), Matrix::new([[1.5, 2.], [0., 3.]]));
+		assert_eq!(
+			Vector::new([[Complex::from_complex(3., -4.), Complex::from_imaginary(-2.)]]).abs(),
+			Vector::new([[Complex::from_real(5.), Complex::from_real(2.)]])
+		)
+	}
+	
+	#[test]
+	fn min_max() {
+		let a = Matrix::new([[1., -2.], [3., 0.]]);
+		let b = Matrix::new([[0., 2.], [3., -1.]]);
+		assert_eq!(a.min(&b), Matrix::new([[0., -2.], [3., -1.]]));
+		assert_eq!(a.max(&b), Matrix::new([[1., 2.], [3., 0.]]))
+	}
+}
+
+#[cfg(test)]
+mod row_column {
+	use super::*;
+	
+	#[test]
+	fn matrix_column() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+		assert_eq!(a * ColVector::new([1, 0, -1]), ColVector::new([-2, -2]));
+		let x = ColVector::new([Complex::from_real(1.), Complex::from_imaginary(1.)]);
+		let b = Matrix::new([[Complex::from_real(0.), Complex::from_real(1.)], [Complex::from_imaginary(1.), Complex::from_real(0.)]]);
+		assert_eq!(b * x, ColVector::new([Complex::from_imaginary(1.), Complex::from_imaginary(1.)]))
+	}
+	
+	#[test]
+	fn row_matrix() {
+		let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+		assert_eq!(RowVector::new([1, -1]) * a, RowVector::new([-3, -3, -3]));
+		assert_eq!((RowVector::new([1, -1]) * a).transpose(), a.transpose() * ColVector::new([1, -1]))
+	}
+	
+	#[test]
+	fn conversions() {
+		let row = RowVector::new([1, 2, 3]);
+		let column = ColVector::new([1, 2, 3]);
+		assert_eq!(Vector::from(row), Vector::new([[1, 2, 3]]));
+		assert_eq!(Matrix::from(column), Matrix::new([[1], [2], [3]]));
+		assert_eq!(RowVector::from(Vector::new([[1, 2, 3]])), row);
+		assert_eq!(ColVector::from(Matrix::new([[1], [2], [3]])), column);
+		assert_eq!(ColVector::from(row), column);
+		assert_eq!(RowVector::from(column), row);
+		assert_eq!(row.transpose().transpose(), row)
+	}
+	
+	#[test]
+	fn index() {
+		let mut column = ColVector::new([1., 2.]);
+		column[1] = 5.;
+		assert_eq!(column[0], 1.);
+		assert_eq!(column, ColVector::new([1., 5.]));
+		let mut row = RowVector::new([1, 2]);
+		row[0] -= 3;
+		assert_eq!(row, RowVector::new([-2, 2]))
+	}	
+	#[test]
+	fn vector_ops() {
+		let (row, column) = (RowVector::new([3., 4.]), ColVector::new([3., 4.]));
+		assert_eq!(row.dot(&RowVector::new([1., -1.])), -1.);
+		assert_eq!(column.dot(&column), 25.);
+		assert_eq!((row.norm(), column.norm()), (5., 5.));
+		assert_eq!(row.normalize(), Some(RowVector::new([0.6, 0.8])));
+		assert_eq!(column.normalize(), Some(ColVector::new([0.6, 0.8])));
+		assert_eq!(ColVector::new([0., 0.]).normalize(), None);
+		let a = RowVector::new([Complex::from_imaginary(1), Complex::from_real(1)]);
+		assert_eq!(a.dot(&a), Complex::from_real(2))
+	}
+	
+	#[test]
+	fn add_sub() {
+		assert_eq!(RowVector::new([1, 2]) + RowVector::new([3, -4]), RowVector::new([4, -2]));
+		assert_eq!(RowVector::new([1, 2]) - RowVector::new([3, -4]), RowVector::new([-2, 6]));
+		assert_eq!(ColVector::new([1, 2]) + ColVector::new([3, -4]), ColVector::new([4, -2]));
+		assert_eq!(ColVector::new([1, 2]) - ColVector::new([3, -4]), ColVector::new([-2, 6]))
+	}
+}